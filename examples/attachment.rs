@@ -5,8 +5,6 @@ use std::env;
 use std::io::{Error, ErrorKind};
 use std::time::Instant;
 
-use smartsheet_rs;
-
 #[macro_use]
 extern crate log;
 
@@ -7,7 +7,6 @@ use std::time::Instant;
 
 use serde_json::to_string_pretty;
 
-use smartsheet_rs;
 use smartsheet_rs::models::{AttachmentMeta, IndexResult};
 
 #[macro_use]
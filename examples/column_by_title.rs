@@ -8,8 +8,6 @@ use std::io::{Error, ErrorKind};
 use std::str::FromStr;
 use std::time::Instant;
 
-use smartsheet_rs;
-
 // A simple type alias so as to DRY.
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
@@ -5,7 +5,6 @@ use std::env;
 use std::io::{Error, ErrorKind};
 use std::time::Instant;
 
-use smartsheet_rs;
 use smartsheet_rs::models::Column;
 
 use log::error;
@@ -90,7 +89,7 @@ async fn main() -> Result<()> {
 /// Print details on the first column in the response
 // noinspection DuplicatedCode
 #[allow(dead_code)]
-async fn print_info_on_first_column(columns: &Vec<Column>) -> Result<()> {
+async fn print_info_on_first_column(columns: &[Column]) -> Result<()> {
     if let Some(column) = columns.first() {
         println!();
         println!("First Column:");
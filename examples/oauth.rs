@@ -0,0 +1,59 @@
+#![deny(warnings)]
+#![warn(rust_2018_idioms)]
+
+use std::env;
+use std::io::{stdin, Error, ErrorKind};
+use std::time::Instant;
+
+use smartsheet_rs::auth::oauth::{OAuthClient, OAuthScope};
+
+#[macro_use]
+extern crate log;
+
+// A simple type alias so as to DRY.
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+// noinspection DuplicatedCode
+fn env_var(name: &str) -> Result<String> {
+    match env::var(name) {
+        Ok(value) => Ok(value),
+        Err(_) => {
+            let msg = format!("Environment variable `{}` must be set.", name);
+            error!("{}", msg);
+            Err(Box::new(Error::new(ErrorKind::NotFound, msg)))
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    sensible_env_logger::init!();
+
+    let client_id = env_var("SMARTSHEET_CLIENT_ID")?;
+    let client_secret = env_var("SMARTSHEET_CLIENT_SECRET")?;
+
+    let oauth = OAuthClient::new(client_id, client_secret);
+
+    let url = oauth.authorize_url(&[OAuthScope::ReadSheets], "my-state");
+
+    println!("Open the following URL in a browser, and approve the app:");
+    println!("  {}", url);
+    println!("Then, enter the `code` from the redirect URL:");
+
+    let mut code = String::new();
+    stdin().read_line(&mut code)?;
+
+    let start = Instant::now();
+
+    let token = oauth.exchange_code(code.trim()).await?;
+
+    trace!("Exchange Code completed in {:.2?}", start.elapsed());
+
+    let smart = smartsheet_rs::SmartsheetApi::from_provider(oauth.into_provider(token));
+
+    let sheets = smart.list_sheets().await?;
+
+    debug!("Sheet Count:  {}", sheets.total_count);
+
+    Ok(())
+}
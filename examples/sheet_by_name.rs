@@ -7,7 +7,6 @@ use std::env;
 use std::io::{Error, ErrorKind};
 use std::time::Instant;
 
-use smartsheet_rs;
 use smartsheet_rs::models::{Column, Row};
 use smartsheet_rs::{CellGetter, ColumnMapper};
 
@@ -73,7 +72,7 @@ async fn main() -> Result<()> {
 /// For each cell in the row, print out columns name and cell values
 // noinspection DuplicatedCode
 #[allow(dead_code)]
-async fn print_column_names_and_cell_values(row: Option<&Row>, cols: &Vec<Column>) -> Result<()> {
+async fn print_column_names_and_cell_values(row: Option<&Row>, cols: &[Column]) -> Result<()> {
     if row.is_none() {
         return Ok(());
     }
@@ -92,7 +91,7 @@ async fn print_column_names_and_cell_values(row: Option<&Row>, cols: &Vec<Column
     println!("Column Names and Values");
     println!("---");
 
-    for (col_name, _col_id) in &cols.name_to_id {
+    for col_name in cols.name_to_id.keys() {
         if let Ok(cell) = get_cell.by_name(row, col_name) {
             println!("Column Name: {}", col_name);
             // Print out the cell value
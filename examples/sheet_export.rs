@@ -0,0 +1,63 @@
+#![deny(warnings)]
+#![warn(rust_2018_idioms)]
+
+use std::env;
+use std::io::{Error, ErrorKind};
+use std::time::Instant;
+
+use smartsheet_rs::models::{ExportFormat, PaperSize};
+
+#[macro_use]
+extern crate log;
+
+// A simple type alias so as to DRY.
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+const USAGE: &str = "Usage: sheet_export <sheet_id> <csv|xls|pdf>";
+
+// noinspection DuplicatedCode
+async fn fetch_args() -> Result<(u64, ExportFormat, &'static str)> {
+    // Some simple CLI args requirements...
+    let sheet_id = match env::args().nth(1) {
+        Some(value) => value.parse::<u64>()?,
+        None => {
+            error!("{}", USAGE);
+            return Err(Box::new(Error::new(ErrorKind::InvalidInput, USAGE)));
+        }
+    };
+
+    let (format, ext) = match env::args().nth(2).as_deref() {
+        Some("csv") => (ExportFormat::Csv, "csv"),
+        Some("xls") => (ExportFormat::Excel, "xls"),
+        Some("pdf") => (ExportFormat::Pdf(PaperSize::Letter), "pdf"),
+        _ => {
+            error!("{}", USAGE);
+            return Err(Box::new(Error::new(ErrorKind::InvalidInput, USAGE)));
+        }
+    };
+
+    Ok((sheet_id, format, ext))
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    sensible_env_logger::init!();
+
+    let (sheet_id, format, ext) = fetch_args().await?;
+
+    let smart = smartsheet_rs::SmartsheetApi::from_env()?;
+
+    let start = Instant::now();
+
+    let body = smart.export_sheet(sheet_id, format).await?;
+    let bytes = hyper::body::to_bytes(body).await?;
+
+    trace!("Export Sheet completed in {:.2?}", start.elapsed());
+
+    let file_name = format!("{}.{}", sheet_id, ext);
+    std::fs::write(&file_name, &bytes)?;
+
+    trace!("Wrote {} bytes to `{}`", bytes.len(), file_name);
+
+    Ok(())
+}
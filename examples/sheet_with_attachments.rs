@@ -8,7 +8,6 @@ use std::time::Instant;
 
 use serde_json::to_string_pretty;
 
-use smartsheet_rs;
 use smartsheet_rs::models::RowIncludeFlags::Attachments;
 use smartsheet_rs::models::{AttachmentMeta, SheetIncludeFlags};
 
@@ -93,7 +92,7 @@ fn print_row_attachments(
 
     trace!("Sheet ID: {}", sheet_id);
 
-    let first_row_attachments = match row_to_attach.iter().nth(0) {
+    let first_row_attachments = match row_to_attach.iter().next() {
         Some((_row_id, attachments)) => attachments,
         None => return Ok(()),
     };
@@ -111,7 +110,7 @@ fn print_first_row_attachment(
     sheet_id: u64,
     row_to_attach: &HashMap<u64, Vec<AttachmentMeta>>,
 ) -> Result<()> {
-    let first_row_attachments = match row_to_attach.iter().nth(0) {
+    let first_row_attachments = match row_to_attach.iter().next() {
         Some((_row_id, attachments)) => attachments,
         None => return Ok(()),
     };
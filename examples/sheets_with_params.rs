@@ -3,7 +3,6 @@
 
 use std::time::Instant;
 
-use smartsheet_rs;
 use smartsheet_rs::models::{ListSheetIncludeFlags, Sheet};
 #[macro_use]
 extern crate log;
@@ -66,7 +65,7 @@ async fn main() -> Result<()> {
         rows.push(TableRow {
             id: sheet.id,
             name: &sheet.name,
-            owner: &sheet.owner.as_ref().unwrap(),
+            owner: sheet.owner.as_ref().unwrap(),
         });
     }
 
@@ -89,7 +88,7 @@ async fn main() -> Result<()> {
 /// Print details on the first sheet in the response
 // noinspection DuplicatedCode
 #[allow(dead_code)]
-async fn print_info_on_first_sheet(sheets: &Vec<Sheet>) -> Result<()> {
+async fn print_info_on_first_sheet(sheets: &[Sheet]) -> Result<()> {
     if let Some(sheet) = sheets.first() {
         debug!("First Sheet:");
         debug!("---");
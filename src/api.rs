@@ -1,43 +1,75 @@
 //! Smartsheet API v2 implementation in Rust
 //!
-use crate::auth::auth_token;
+use crate::auth::{auth_token, StaticToken, TokenProvider};
 use crate::builders::ParamBuilder;
-use crate::constants::{API_ENDPOINT, ENV_VAR_NAME};
-use crate::https::{get_https_client, tls};
+use crate::constants::{
+    API_ENDPOINT, ASSUME_USER_HEADER, CHANGE_AGENT_HEADER, ENV_VAR_NAME, REGION_ENV_VAR_NAME,
+};
+use crate::decompress::{accept_encoding, decompress};
+use crate::https::{build_https_client, HttpVersion, HttpsClient, HttpsConfig};
 use crate::log::{debug, warn};
+use crate::middleware::Middleware;
 use crate::models::*;
-use crate::status::raise_for_status;
+use crate::proxy::Proxy;
+use crate::rate_limit::RateLimiter;
+use crate::region::Region;
+use crate::response::{self, ResponseMeta};
+use crate::status::{is_rejected_token, raise_for_status};
+use crate::telemetry::RequestSpan;
+use crate::tls::TlsConfig;
 use crate::types::Result;
 use crate::utils::*;
 
+use std::future::Future;
 use std::io::{Error, ErrorKind};
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
-use hyper::client::HttpConnector;
-use hyper::header::AUTHORIZATION;
-use hyper::{Body, Client, Method, Request};
+use hyper::header::{
+    HeaderMap, HeaderValue, ACCEPT, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_DISPOSITION,
+    CONTENT_LENGTH, CONTENT_TYPE,
+};
+use hyper::{Body, Method, Request, Response, StatusCode};
+use serde::de::DeserializeOwned;
 
 /// Client implementation for making requests to the *Smartsheet
 /// API v2*
 ///
+/// The client is cheap to clone, and clones share the same connection
+/// pool; so it can be stored in application state, or moved into spawned
+/// tasks, as needed.
+///
 /// # Links
 /// - [`smartsheet-rs`](https://docs.rs/smartsheet-rs)
 /// - [Official Documentation](https://smartsheet-platform.github.io/api-docs/)
 ///
-pub struct SmartsheetApi<'a> {
-    bearer_token: String,
-    client: Client<tls::HttpsConnector<HttpConnector>>,
-    endpoint: &'a str,
+#[derive(Clone)]
+pub struct SmartsheetApi {
+    tokens: Arc<dyn TokenProvider>,
+    client: HttpsClient,
+    https: HttpsConfig,
+    endpoint: Arc<str>,
+    region: Region,
+    headers: Arc<HeaderMap>,
+    meta: Option<Arc<Mutex<Option<ResponseMeta>>>>,
+    limiter: Option<RateLimiter>,
+    middleware: Arc<Vec<Arc<dyn Middleware>>>,
 }
 
-impl<'a> SmartsheetApi<'a> {
+impl SmartsheetApi {
     /// Initialize a new `SmartsheetApi` object from an API access token.
     pub fn from_token(token: &str) -> Self {
-        Self::new(API_ENDPOINT, token)
+        Self::new(API_ENDPOINT, StaticToken::new(token))
     }
 
     /// Initialize a new `SmartsheetApi` object from an API access token,
     /// assuming this is currently set in the environment.
+    ///
+    /// The `Region` can also be set in the environment, via the
+    /// `SMARTSHEET_REGION` variable; if not set, this defaults to `US`.
+    ///
+    /// Note: the token is only read once; use `EnvToken` with
+    /// `SmartsheetApi::from_provider` to read it before each request.
     pub fn from_env() -> Result<Self> {
         let token: String = match std::env::var(ENV_VAR_NAME) {
             Ok(val) => Ok(val),
@@ -50,27 +82,251 @@ impl<'a> SmartsheetApi<'a> {
             )),
         }?;
 
-        Ok(Self::new(API_ENDPOINT, &token))
+        let region = match std::env::var(REGION_ENV_VAR_NAME) {
+            Ok(val) => val.parse()?,
+            Err(_) => Region::default(),
+        };
+
+        Ok(Self::from_region_and_token(region, &token))
+    }
+
+    /// Initialize a new `SmartsheetApi` object from a `Region`, and an
+    /// access token.
+    pub fn from_region_and_token(region: Region, token: &str) -> Self {
+        Self::new(region.api_endpoint(), StaticToken::new(token))
+    }
+
+    /// Initialize a new `SmartsheetApi` object from a `Region`, and a
+    /// `TokenProvider`.
+    pub fn from_region_and_provider(
+        region: Region,
+        provider: impl TokenProvider + 'static,
+    ) -> Self {
+        Self::new(region.api_endpoint(), provider)
     }
 
     /// Initialize a new `SmartsheetApi` object from a (custom) base API
     /// endpoint, and an access token.
-    pub fn from_endpoint_and_token(endpoint: &'a str, token: &str) -> Self {
-        Self::new(endpoint, token)
+    ///
+    /// The `Region` is determined from the endpoint, defaulting to `US`.
+    ///
+    /// The endpoint must use `https`, so that the access token is never
+    /// sent in cleartext; requests to an `http` endpoint fail.
+    pub fn from_endpoint_and_token(endpoint: &str, token: &str) -> Self {
+        Self::new(endpoint, StaticToken::new(token))
+    }
+
+    /// Initialize a new `SmartsheetApi` object from a `TokenProvider`,
+    /// which supplies the access token before each request - for example,
+    /// an `OAuthTokenProvider`.
+    pub fn from_provider(provider: impl TokenProvider + 'static) -> Self {
+        Self::new(API_ENDPOINT, provider)
+    }
+
+    /// Initialize a new `SmartsheetApi` object from a (custom) base API
+    /// endpoint, and a `TokenProvider`.
+    pub fn from_endpoint_and_provider(
+        endpoint: &str,
+        provider: impl TokenProvider + 'static,
+    ) -> Self {
+        Self::new(endpoint, provider)
     }
 
     /// Constructor function, for internal use
-    fn new(endpoint: &'a str, token: &str) -> Self {
-        let bearer_token = auth_token(token);
-        let client = get_https_client();
+    fn new(endpoint: &str, provider: impl TokenProvider + 'static) -> Self {
+        let tokens = Arc::new(provider);
+        let https = HttpsConfig::from_env();
+        let client = build_https_client(&https);
+        let region = Region::from_endpoint(endpoint);
 
         Self {
-            bearer_token,
+            tokens,
             client,
-            endpoint,
+            https,
+            endpoint: Arc::from(endpoint),
+            region,
+            headers: Arc::new(HeaderMap::new()),
+            meta: None,
+            limiter: None,
+            middleware: Arc::new(Vec::new()),
+        }
+    }
+
+    /// Returns the `Region` of the Smartsheet API which the client makes
+    /// requests to.
+    pub fn region(&self) -> Region {
+        self.region
+    }
+
+    /// Returns a copy of the client which makes requests on behalf of the
+    /// user with the specified `email`, via the `Assume-User` header.
+    ///
+    /// This requires an access token for a System Admin. It can be set
+    /// once when the client is created, or per call as follows:
+    ///
+    /// ```no_run
+    /// # async fn run(smart: smartsheet_rs::SmartsheetApi) {
+    /// let sheets = smart.as_user("john.doe@example.com").list_sheets().await;
+    /// # }
+    /// ```
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#section/Work-at-Scale/Assume-User
+    ///
+    pub fn as_user(&self, email: &str) -> Self {
+        let mut api = self.clone();
+        // The email address must be URI-encoded, as per the docs
+        let value = HeaderValue::from_str(&encode_uri_component(email))
+            .expect("a URI-encoded value is a valid header value");
+        Arc::make_mut(&mut api.headers).insert(ASSUME_USER_HEADER, value);
+
+        api
+    }
+
+    /// Returns a copy of the client which waits for requests to fit within
+    /// the rate limit of a `RateLimiter`, rather than fail with a
+    /// `429 Too Many Requests` error.
+    ///
+    /// The rate limit budget is shared by all clones of the client.
+    pub fn with_rate_limiter(&self, limiter: RateLimiter) -> Self {
+        let mut api = self.clone();
+        api.limiter = Some(limiter);
+
+        api
+    }
+
+    /// Returns a copy of the client which connects to the Smartsheet API via
+    /// a `Proxy`, or directly if `None`.
+    ///
+    /// By default, the proxy is set from the `HTTPS_PROXY` and `NO_PROXY`
+    /// environment variables. The copy has its own connection pool.
+    pub fn with_proxy(&self, proxy: impl Into<Option<Proxy>>) -> Self {
+        let mut api = self.clone();
+        api.https.proxy = proxy.into();
+        api.client = build_https_client(&api.https);
+
+        api
+    }
+
+    /// Returns a copy of the client which uses a custom `TlsConfig` for the
+    /// connections to the Smartsheet API - for example, to trust a private
+    /// CA, or to pin the certificate of the server.
+    ///
+    /// The copy has its own connection pool; see the [`tls`](crate::tls)
+    /// module for more info.
+    ///
+    /// # Errors
+    /// Returns an error if the TLS configuration is invalid - for example,
+    /// if none of the protocol versions are supported.
+    pub fn with_tls_config(&self, tls: TlsConfig) -> Result<Self> {
+        let mut api = self.clone();
+        api.https.tls = Some(tls.build()?);
+        api.client = build_https_client(&api.https);
+
+        Ok(api)
+    }
+
+    /// Returns a copy of the client which uses an `HttpVersion` for the
+    /// requests to the Smartsheet API.
+    ///
+    /// By default, this is `HttpVersion::Auto` - which uses HTTP/2 if the
+    /// server supports it. The copy has its own connection pool.
+    pub fn with_http_version(&self, version: HttpVersion) -> Self {
+        let mut api = self.clone();
+        api.https.version = version;
+        api.client = build_https_client(&api.https);
+
+        api
+    }
+
+    /// Returns a copy of the client with its own connection pool, with the
+    /// same configuration; for internal use
+    #[cfg(feature = "blocking")]
+    pub(crate) fn with_new_pool(&self) -> Self {
+        let mut api = self.clone();
+        api.client = build_https_client(&api.https);
+
+        api
+    }
+
+    /// Returns a copy of the client, with a `Middleware` added after any
+    /// existing ones.
+    ///
+    /// The middleware hooks are called for each request the client sends;
+    /// see the [`middleware`](crate::middleware) module for more info.
+    pub fn with_middleware(&self, middleware: impl Middleware + 'static) -> Self {
+        let mut api = self.clone();
+        Arc::make_mut(&mut api.middleware).push(Arc::new(middleware));
+
+        api
+    }
+
+    /// Calls one (or more) methods on a copy of the client, and returns the
+    /// result along with the metadata - such as the HTTP status, headers,
+    /// and elapsed time - of the last response.
+    ///
+    /// ```no_run
+    /// # async fn run(smart: smartsheet_rs::SmartsheetApi) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// let res = smart.with_meta(|api| async move { api.list_sheets().await }).await?;
+    ///
+    /// println!("{} took {:?}", res.meta.url, res.meta.elapsed);
+    /// println!("Sheet Count: {}", res.data.total_count);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn with_meta<T, F, Fut>(&self, f: F) -> Result<response::Response<T>>
+    where
+        F: FnOnce(Self) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let (api, slot) = self.recording_meta();
+
+        let data = f(api).await?;
+
+        Self::take_meta(&slot, data)
+    }
+
+    /// Returns a copy of the client which records the metadata of each
+    /// response in the returned slot, for internal use
+    pub(crate) fn recording_meta(&self) -> (Self, Arc<Mutex<Option<ResponseMeta>>>) {
+        let slot = Arc::new(Mutex::new(None));
+
+        let mut api = self.clone();
+        api.meta = Some(slot.clone());
+
+        (api, slot)
+    }
+
+    /// Pairs `data` with the metadata of the last response recorded in a
+    /// `slot`, for internal use
+    pub(crate) fn take_meta<T>(
+        slot: &Mutex<Option<ResponseMeta>>,
+        data: T,
+    ) -> Result<response::Response<T>> {
+        let meta = slot.lock().unwrap().take();
+
+        match meta {
+            Some(meta) => Ok(response::Response { data, meta }),
+            None => Err(Box::from(Error::new(
+                ErrorKind::NotFound,
+                "No request was made, so there is no response metadata",
+            ))),
         }
     }
 
+    /// Returns a copy of the client which identifies its changes as being
+    /// made by the integration `agent`, via the `Smartsheet-Change-Agent`
+    /// header.
+    ///
+    /// # Errors
+    /// Returns an error if `agent` is not a valid header value.
+    pub fn with_change_agent(&self, agent: &str) -> Result<Self> {
+        let mut api = self.clone();
+        Arc::make_mut(&mut api.headers).insert(CHANGE_AGENT_HEADER, HeaderValue::from_str(agent)?);
+
+        Ok(api)
+    }
+
     /// **List Sheets** - Gets a list of all sheets that the user has access
     /// to in alphabetical order by name. The list contains an abbreviated
     /// Sheet object for each sheet.
@@ -91,8 +347,8 @@ impl<'a> SmartsheetApi<'a> {
     /// * `include` - A comma-separated list of elements to include in the response.
     /// * `include_all` - If true, include all results (i.e. do not paginate).
     /// * `modified_since` - Return sheets modified since a provided datetime.
-    ///                      Date should be in ISO-8601 format, for example,
-    ///                      `2020-01-30T13:25:32-07:00`.
+    ///   Date should be in ISO-8601 format, for example,
+    ///   `2020-01-30T13:25:32-07:00`.
     ///
     /// # Docs
     /// - https://smartsheet-platform.github.io/api-docs/#list-sheets
@@ -101,7 +357,7 @@ impl<'a> SmartsheetApi<'a> {
         &self,
         include: impl Into<Option<Vec<ListSheetIncludeFlags>>>,
         include_all: impl Into<Option<bool>>,
-        modified_since: impl Into<Option<&'a str>>, // TODO change this to a DATE type maybe
+        modified_since: impl Into<Option<&str>>, // TODO change this to a DATE type maybe
     ) -> Result<IndexResult<Sheet>> {
         let mut url = format!("{}/{}", self.endpoint, "sheets");
 
@@ -113,12 +369,9 @@ impl<'a> SmartsheetApi<'a> {
 
         debug!("URL: {}", url);
 
-        let req = Request::get(&url)
-            .header(AUTHORIZATION, &self.bearer_token)
-            .body(Body::empty())?;
+        let req = Request::get(&url).body(Body::empty())?;
 
-        let mut res = self.client.request(req).await?;
-        raise_for_status(url, &mut res).await?;
+        let res = self.send(req).await?;
 
         let start = Instant::now();
 
@@ -189,17 +442,17 @@ impl<'a> SmartsheetApi<'a> {
     /// * `include` - A comma-separated list of elements to include in the response.
     /// * `exclude` - A comma-separated list of elements to _not_ include in the response.
     /// * `row_ids` - A comma-separated list of Row IDs on which to filter the
-    ///               rows included in the result.
+    ///   rows included in the result.
     /// * `row_numbers` - A comma-separated list of Row numbers on which to
-    ///                   filter the rows included in the result. Non-existent
-    ///                   row numbers are ignored.
+    ///   filter the rows included in the result. Non-existent
+    ///   row numbers are ignored.
     /// * `column_ids` - A comma-separated comma-separated list of Column IDs.
-    ///                  The response will contain only the specified columns
-    ///                  in the 'columns' array, and individual rows' 'cells'
-    ///                  array will only contain cells in the specified columns.
+    ///   The response will contain only the specified columns
+    ///   in the 'columns' array, and individual rows' 'cells'
+    ///   array will only contain cells in the specified columns.
     /// * `rows_modified_since` - Return rows modified since a provided datetime.
-    ///                           Date should be in ISO-8601 format, for example,
-    ///                           `2020-01-30T13:25:32-07:00`.
+    ///   Date should be in ISO-8601 format, for example,
+    ///   `2020-01-30T13:25:32-07:00`.
     ///
     /// # Docs
     /// - https://smartsheet-platform.github.io/api-docs/#get-sheet
@@ -214,7 +467,7 @@ impl<'a> SmartsheetApi<'a> {
         row_ids: impl Into<Option<Vec<u64>>>,
         row_numbers: impl Into<Option<Vec<u64>>>,
         column_ids: impl Into<Option<Vec<u64>>>,
-        rows_modified_since: impl Into<Option<&'a str>>, // TODO change this to a date type maybe
+        rows_modified_since: impl Into<Option<&str>>, // TODO change this to a date type maybe
         level: impl Into<Option<Level>>,
     ) -> Result<Sheet> {
         let mut url = format!("{}/{}/{}", self.endpoint, "sheets", sheet_id);
@@ -231,12 +484,9 @@ impl<'a> SmartsheetApi<'a> {
 
         debug!("URL: {}", url);
 
-        let req = Request::get(&url)
-            .header(AUTHORIZATION, &self.bearer_token)
-            .body(Body::empty())?;
+        let req = Request::get(&url).body(Body::empty())?;
 
-        let mut res = self.client.request(req).await?;
-        raise_for_status(url, &mut res).await?;
+        let res = self.send(req).await?;
 
         let start = Instant::now();
 
@@ -260,6 +510,75 @@ impl<'a> SmartsheetApi<'a> {
         Ok(sheet)
     }
 
+    /// **Get Sheet as Excel / PDF / CSV** - Retrieves the specified sheet,
+    /// rendered by Smartsheet in the requested export format.
+    ///
+    /// The response body is returned as-is, so it can be streamed to a file
+    /// or collected with `hyper::body::to_bytes`.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to export.
+    /// * `format` - The format to export the sheet in. For the `Pdf` format,
+    ///   this also determines the paper size of the document.
+    ///
+    /// # Docs
+    /// - https://smartsheet-platform.github.io/api-docs/#get-sheet-as-excel-pdf-csv
+    ///
+    pub async fn export_sheet(&self, sheet_id: u64, format: ExportFormat) -> Result<Body> {
+        let mut url = format!("{}/{}/{}", self.endpoint, "sheets", sheet_id);
+
+        ParamBuilder::new(&mut url)
+            .with_value("paperSize", format.paper_size())
+            .build();
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url)
+            .header(ACCEPT, format.content_type())
+            .body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        Ok(res.into_body())
+    }
+
+    /// **Get Report as Excel / CSV** - Retrieves the specified report,
+    /// rendered by Smartsheet in the requested export format.
+    ///
+    /// The response body is returned as-is, so it can be streamed to a file
+    /// or collected with `hyper::body::to_bytes`.
+    ///
+    /// # Arguments
+    ///
+    /// * `report_id` - The report to export.
+    /// * `format` - The format to export the report in. Note that reports
+    ///   cannot be exported as PDF.
+    ///
+    /// # Docs
+    /// - https://smartsheet-platform.github.io/api-docs/#get-report-as-excel-csv
+    ///
+    pub async fn export_report(&self, report_id: u64, format: ExportFormat) -> Result<Body> {
+        if let ExportFormat::Pdf(_) = format {
+            return Err(Box::from(Error::new(
+                ErrorKind::InvalidInput,
+                "Reports can only be exported as CSV or Excel",
+            )));
+        }
+
+        let url = format!("{}/{}/{}", self.endpoint, "reports", report_id);
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url)
+            .header(ACCEPT, format.content_type())
+            .body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        Ok(res.into_body())
+    }
+
     /// **Get Row** - Retrieves the specified row from a sheet.
     ///
     /// # Arguments
@@ -328,7 +647,7 @@ impl<'a> SmartsheetApi<'a> {
     /// * `include` - A comma-separated list of elements to include in the response.
     /// * `exclude` - A comma-separated list of elements to _not_ include in the response.
     /// * `level` - Specifies whether multi-contact data is returned in a
-    ///             backwards-compatible, text format, or as multi-contact data.
+    ///   backwards-compatible, text format, or as multi-contact data.
     ///
     /// # Docs
     /// - https://smartsheet-platform.github.io/api-docs/#get-row
@@ -355,12 +674,9 @@ impl<'a> SmartsheetApi<'a> {
 
         debug!("URL: {}", url);
 
-        let req = Request::get(&url)
-            .header(AUTHORIZATION, &self.bearer_token)
-            .body(Body::empty())?;
+        let req = Request::get(&url).body(Body::empty())?;
 
-        let mut res = self.client.request(req).await?;
-        raise_for_status(url, &mut res).await?;
+        let res = self.send(req).await?;
 
         let start = Instant::now();
 
@@ -404,12 +720,12 @@ impl<'a> SmartsheetApi<'a> {
     /// * `sheet_id` - The Smartsheet to add the rows to.
     /// * `rows` - An array (list) of new Rows with the cell values to add.
     /// * `allow_partial_success` - Default: `false`. When specified with a value
-    ///               of `true`, enables partial success for this bulk operation.
-    ///               See [Partial Success] for more information.
+    ///   of `true`, enables partial success for this bulk operation.
+    ///   See [Partial Success] for more information.
     /// * `override_validation` - Default: `false`. If set to a value of `true`,
-    ///               allows a cell value outside of the validation limits. You
-    ///               must also specify **strict** on a per-cell level with a
-    ///               value of **false** to bypass value type checking.
+    ///   allows a cell value outside of the validation limits. You
+    ///   must also specify **strict** on a per-cell level with a
+    ///   value of **false** to bypass value type checking.
     ///
     /// [Partial Success]: https://smartsheet.redoc.ly/#section/Work-at-Scale/Bulk-Operations
     ///
@@ -469,12 +785,12 @@ impl<'a> SmartsheetApi<'a> {
     /// * `sheet_id` - The Smartsheet to update the rows in.
     /// * `rows` - An array (list) of Rows with the updated cell values.
     /// * `allow_partial_success` - When specified with a value of `true`, enables
-    ///               partial success for this bulk operation. See [Partial
-    ///               Success] for more information.
+    ///   partial success for this bulk operation. See [Partial
+    ///   Success] for more information.
     /// * `override_validation` - If set to a value of `true`, allows a cell value
-    ///               outside of the validation limits. You must also specify **strict**
-    ///               on a per-cell level with a value of **false** to bypass value
-    ///               type checking.
+    ///   outside of the validation limits. You must also specify **strict**
+    ///   on a per-cell level with a value of **false** to bypass value
+    ///   type checking.
     ///
     /// [Partial Success]: https://smartsheet.redoc.ly/#section/Work-at-Scale/Bulk-Operations
     ///
@@ -522,11 +838,9 @@ impl<'a> SmartsheetApi<'a> {
         let req = Request::builder()
             .method(method)
             .uri(&url)
-            .header(AUTHORIZATION, &self.bearer_token)
             .body(Body::from(data))?;
 
-        let mut res = self.client.request(req).await?;
-        raise_for_status(url, &mut res).await?;
+        let res = self.send(req).await?;
 
         let start = Instant::now();
 
@@ -544,7 +858,7 @@ impl<'a> SmartsheetApi<'a> {
     ///
     /// * `sheet_id` - The Smartsheet to delete the rows from.
     /// * `row_ids` - An array (list) containing the IDs of the Rows to
-    ///               delete from the smartsheet.
+    ///   delete from the smartsheet.
     ///
     /// # Docs
     /// - <https://smartsheet.redoc.ly/#operation/delete-rows>
@@ -564,10 +878,10 @@ impl<'a> SmartsheetApi<'a> {
     ///
     /// * `sheet_id` - The Smartsheet to delete the rows from.
     /// * `row_ids` - An array (list) containing the IDs of the Rows to
-    ///               delete from the smartsheet.
+    ///   delete from the smartsheet.
     /// * `ignore_rows_not_found` -  Default: `false`. If set to `false` and any of
-    ///              the specified Row IDs are not found, no rows are deleted,
-    ///              and the "not found" error is returned.
+    ///   the specified Row IDs are not found, no rows are deleted,
+    ///   and the "not found" error is returned.
     ///
     /// # Docs
     /// - <https://smartsheet.redoc.ly/#operation/delete-rows>
@@ -588,12 +902,9 @@ impl<'a> SmartsheetApi<'a> {
 
         debug!("URL: {}", url);
 
-        let req = Request::delete(&url)
-            .header(AUTHORIZATION, &self.bearer_token)
-            .body(Body::empty())?;
+        let req = Request::delete(&url).body(Body::empty())?;
 
-        let mut res = self.client.request(req).await?;
-        raise_for_status(url, &mut res).await?;
+        let res = self.send(req).await?;
 
         let start = Instant::now();
 
@@ -622,7 +933,7 @@ impl<'a> SmartsheetApi<'a> {
     ///
     /// * `sheet_id` - The Smartsheet to retrieve the columns from.
     /// * `level` - Specifies whether multi-contact data is returned in a
-    ///             backwards-compatible, text format, or as multi-contact data.
+    ///   backwards-compatible, text format, or as multi-contact data.
     /// * `include` - A comma-separated list of elements to include in the response.
     /// * `include_all` - If true, include all results (i.e. do not paginate).
     ///
@@ -646,12 +957,9 @@ impl<'a> SmartsheetApi<'a> {
 
         debug!("URL: {}", url);
 
-        let req = Request::get(&url)
-            .header(AUTHORIZATION, &self.bearer_token)
-            .body(Body::empty())?;
+        let req = Request::get(&url).body(Body::empty())?;
 
-        let mut res = self.client.request(req).await?;
-        raise_for_status(url, &mut res).await?;
+        let res = self.send(req).await?;
 
         let start = Instant::now();
 
@@ -685,7 +993,7 @@ impl<'a> SmartsheetApi<'a> {
     /// * `sheet_id` - The Smartsheet to retrieve the column for.
     /// * `column_id` - The Column Id to retrieve the data for.
     /// * `level` - Specifies whether multi-contact data is returned in a
-    ///             backwards-compatible, text format, or as multi-contact data.
+    ///   backwards-compatible, text format, or as multi-contact data.
     /// * `include` - A comma-separated list of elements to include in the response.
     ///
     /// # Docs
@@ -710,12 +1018,9 @@ impl<'a> SmartsheetApi<'a> {
 
         debug!("URL: {}", url);
 
-        let req = Request::get(&url)
-            .header(AUTHORIZATION, &self.bearer_token)
-            .body(Body::empty())?;
+        let req = Request::get(&url).body(Body::empty())?;
 
-        let mut res = self.client.request(req).await?;
-        raise_for_status(url, &mut res).await?;
+        let res = self.send(req).await?;
 
         let start = Instant::now();
 
@@ -744,12 +1049,9 @@ impl<'a> SmartsheetApi<'a> {
 
         debug!("URL: {}", url);
 
-        let req = Request::get(&url)
-            .header(AUTHORIZATION, &self.bearer_token)
-            .body(Body::empty())?;
+        let req = Request::get(&url).body(Body::empty())?;
 
-        let mut res = self.client.request(req).await?;
-        raise_for_status(url, &mut res).await?;
+        let res = self.send(req).await?;
 
         let start = Instant::now();
 
@@ -784,12 +1086,9 @@ impl<'a> SmartsheetApi<'a> {
 
         debug!("URL: {}", url);
 
-        let req = Request::get(&url)
-            .header(AUTHORIZATION, &self.bearer_token)
-            .body(Body::empty())?;
+        let req = Request::get(&url).body(Body::empty())?;
 
-        let mut res = self.client.request(req).await?;
-        raise_for_status(url, &mut res).await?;
+        let res = self.send(req).await?;
 
         let start = Instant::now();
 
@@ -800,95 +1099,2343 @@ impl<'a> SmartsheetApi<'a> {
         Ok(attachment)
     }
 
-    /// **Get Sheet By Name** - Convenience function to retrieve a specified
-    /// sheet by name. Used for those times when you don't know the Sheet Id.
+    /// **Attach File to Sheet** - Attaches a file to the sheet, by uploading
+    /// it.
     ///
-    /// This will internally call `list_sheets` and then filter the response
-    /// data by the sheet name. It returns the first matching name.
+    /// # Arguments
     ///
-    /// Returns the sheet, including rows, and optionally populated with
-    /// discussion and attachment objects.
+    /// * `sheet_id` - The Smartsheet to attach the file to.
+    /// * `file_name` - The name of the file to upload.
+    /// * `content_type` - The MIME type of the file, for example `text/csv`.
+    /// * `data` - The contents of the file.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/attachments-attachToSheet
+    ///
+    pub async fn attach_file_to_sheet(
+        &self,
+        sheet_id: u64,
+        file_name: &str,
+        content_type: &str,
+        data: impl Into<Vec<u8>>,
+    ) -> Result<ItemResult<AttachmentMeta>> {
+        let url = format!(
+            "{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "attachments"
+        );
+
+        self.upload_file(url, file_name, content_type, data).await
+    }
+
+    /// **Attach File to Row** - Attaches a file to a row, by uploading it.
     ///
     /// # Arguments
     ///
-    /// * `sheet_name` - The name of the Smartsheet to filter results by.
+    /// * `sheet_id` - The Smartsheet which contains the row.
+    /// * `row_id` - The Row Id to attach the file to.
+    /// * `file_name` - The name of the file to upload.
+    /// * `content_type` - The MIME type of the file, for example `text/csv`.
+    /// * `data` - The contents of the file.
     ///
-    #[deprecated(
-        since = "0.2.0",
-        note = "please cache the sheet id and use `get_sheet` instead"
-    )]
-    pub async fn get_sheet_by_name(&self, sheet_name: &'a str) -> Result<Sheet> {
-        // Display a warning that the usage of this method is not recommended
-        warn!(
-            "{}",
-            "Calling `get_sheet_by_name()` is not recommended; it's \
-                preferable to cache the sheet ID and call \
-                `get_sheet()` instead."
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/row-attachments-attachFile
+    ///
+    pub async fn attach_file_to_row(
+        &self,
+        sheet_id: u64,
+        row_id: u64,
+        file_name: &str,
+        content_type: &str,
+        data: impl Into<Vec<u8>>,
+    ) -> Result<ItemResult<AttachmentMeta>> {
+        let url = format!(
+            "{}/{}/{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "rows", row_id, "attachments"
         );
 
-        // Get a fresh list of sheets
-        let result = self.list_sheets_with_params(None, Some(true), None).await?;
+        self.upload_file(url, file_name, content_type, data).await
+    }
 
-        // Find the sheet by the provided name
-        return match result
-            .data
-            .into_iter()
-            .find(|sheet| sheet.name == sheet_name)
-        {
-            Some(sheet) => Ok(sheet),
-            None => Err(Box::from(Error::new(
-                ErrorKind::NotFound,
-                format!("The provided sheet `{}` was not found", sheet_name),
-            ))),
-        };
+    /// **Attach URL to Sheet** - Attaches a URL - such as a link, or a file
+    /// stored in a cloud service - to the sheet.
+    ///
+    /// # Errors
+    /// Returns an error without making a request if the attachment type
+    /// is not allowed in the client `Region`; for example, Smartsheetgov
+    /// does not allow `DROPBOX`, `EGNYTE`, or `EVERNOTE` attachments.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to attach the URL to.
+    /// * `attachment` - The URL attachment to add.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/attachments-attachToSheet
+    ///
+    pub async fn attach_url_to_sheet(
+        &self,
+        sheet_id: u64,
+        attachment: &UrlAttachment,
+    ) -> Result<ItemResult<AttachmentMeta>> {
+        let url = format!(
+            "{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "attachments"
+        );
+
+        self.attach_url(url, attachment).await
     }
 
-    /// **Get Column By Title** - Convenience function to retrieve a specified
-    /// column by title (name). Used for those times when you don't know the
-    /// Column Id.
+    /// **Attach URL to Row** - Attaches a URL - such as a link, or a file
+    /// stored in a cloud service - to a row.
     ///
-    /// This will internally call `list_columns` and then filter the response
-    /// data by the column title. It returns the first matching name.
+    /// # Errors
+    /// Returns an error without making a request if the attachment type
+    /// is not allowed in the client `Region`; for example, Smartsheetgov
+    /// does not allow `DROPBOX`, `EGNYTE`, or `EVERNOTE` attachments.
     ///
     /// # Arguments
     ///
-    /// * `sheet_id` - The Smartsheet to retrieve the column from.
-    /// * `column_title` - The name of the column to filter results by.
+    /// * `sheet_id` - The Smartsheet which contains the row.
+    /// * `row_id` - The Row Id to attach the URL to.
+    /// * `attachment` - The URL attachment to add.
     ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/row-attachments-attachFile
     ///
-    #[deprecated(
-        since = "0.2.0",
-        note = "please cache the column id and use `get_column` instead"
-    )]
-    pub async fn get_column_by_title(
+    pub async fn attach_url_to_row(
         &self,
         sheet_id: u64,
-        column_title: &'a str,
-    ) -> Result<Column> {
-        // Display a warning that the usage of this method is not recommended
-        warn!(
-            "{}",
-            "Calling `get_column_by_title()` is not recommended; it's \
-                preferable to cache the column ID and call \
-                `get_column()` instead."
+        row_id: u64,
+        attachment: &UrlAttachment,
+    ) -> Result<ItemResult<AttachmentMeta>> {
+        let url = format!(
+            "{}/{}/{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "rows", row_id, "attachments"
         );
 
-        // Get a fresh list of columns
-        let result = self
-            .list_columns_with_params(sheet_id, None, None, Some(true))
-            .await?;
+        self.attach_url(url, attachment).await
+    }
 
-        // Find the column by the provided name
-        return match result
-            .data
-            .into_iter()
-            .find(|column| column.title == column_title)
+    /// Internal method to attach a URL to the specified `url`, after
+    /// checking the attachment type is allowed in the client `Region`.
+    async fn attach_url(
+        &self,
+        url: String,
+        attachment: &UrlAttachment,
+    ) -> Result<ItemResult<AttachmentMeta>> {
+        if !self
+            .region
+            .allows_attachment_type(&attachment.attachment_type)
         {
-            Some(column) => Ok(column),
-            None => Err(Box::from(Error::new(
-                ErrorKind::NotFound,
-                format!("The provided column `{}` was not found", column_title),
-            ))),
-        };
+            return Err(Box::from(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "Attachment type `{:?}` is not allowed in region `{}`",
+                    attachment.attachment_type, self.region
+                ),
+            )));
+        }
+
+        debug!("URL: {}", url);
+
+        let data = serde_json::to_vec(attachment)?;
+
+        let req = Request::post(&url).body(Body::from(data))?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let result = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(result)
+    }
+
+    /// **Get Sheet Summary** - Returns object containing array of summary
+    /// fields. Allows for pagination of results.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the summary for.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/get-sheet-summary
+    ///
+    pub async fn get_sheet_summary(&self, sheet_id: u64) -> Result<SheetSummary> {
+        self.get_sheet_summary_with_params(sheet_id, None, None)
+            .await
+    }
+
+    /// **Get Sheet Summary** - Returns object containing array of summary
+    /// fields, with included _query parameters_.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the summary for.
+    /// * `include` - A comma-separated list of elements to include in the response.
+    /// * `exclude` - A comma-separated list of elements to _not_ include in the response.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/get-sheet-summary
+    ///
+    pub async fn get_sheet_summary_with_params(
+        &self,
+        sheet_id: u64,
+        include: impl Into<Option<Vec<SummaryFieldIncludeFlags>>>,
+        exclude: impl Into<Option<Vec<SummaryFieldExcludeFlags>>>,
+    ) -> Result<SheetSummary> {
+        let mut url = format!("{}/{}/{}/{}", self.endpoint, "sheets", sheet_id, "summary");
+
+        ParamBuilder::new(&mut url)
+            .with_comma_separated_values("include", include.into())
+            .with_comma_separated_values("exclude", exclude.into())
+            .build();
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let summary = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(summary)
+    }
+
+    /// **List Summary Fields** - Returns a list of summary fields on the
+    /// specified sheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the summary fields for.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/list-summary-fields
+    ///
+    pub async fn list_summary_fields(&self, sheet_id: u64) -> Result<IndexResult<SummaryField>> {
+        self.list_summary_fields_with_params(sheet_id, None, None, None)
+            .await
+    }
+
+    /// **List Summary Fields** - Returns a list of summary fields on the
+    /// specified sheet, with included _query parameters_.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the summary fields for.
+    /// * `include` - A comma-separated list of elements to include in the response.
+    /// * `exclude` - A comma-separated list of elements to _not_ include in the response.
+    /// * `include_all` - If true, include all results (i.e. do not paginate).
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/list-summary-fields
+    ///
+    pub async fn list_summary_fields_with_params(
+        &self,
+        sheet_id: u64,
+        include: impl Into<Option<Vec<SummaryFieldIncludeFlags>>>,
+        exclude: impl Into<Option<Vec<SummaryFieldExcludeFlags>>>,
+        include_all: impl Into<Option<bool>>,
+    ) -> Result<IndexResult<SummaryField>> {
+        let mut url = format!(
+            "{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "summary/fields"
+        );
+
+        ParamBuilder::new(&mut url)
+            .with_comma_separated_values("include", include.into())
+            .with_comma_separated_values("exclude", exclude.into())
+            .with_value("includeAll", include_all.into())
+            .build();
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let fields = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(fields)
+    }
+
+    /// **Add Summary Fields** - Creates one or more summary fields for the
+    /// specified sheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to add the summary fields to.
+    /// * `fields` - An array (list) of new summary fields to add.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/add-summary-fields
+    ///
+    pub async fn add_summary_fields(
+        &self,
+        sheet_id: u64,
+        fields: impl Into<Vec<SummaryField>>,
+    ) -> Result<RowResult<SummaryField>> {
+        self.add_summary_fields_with_params(sheet_id, fields, None)
+            .await
+    }
+
+    /// **Add Summary Fields** - Creates one or more summary fields for the
+    /// specified sheet, with included _query parameters_.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to add the summary fields to.
+    /// * `fields` - An array (list) of new summary fields to add.
+    /// * `rename_if_conflict` - Default: `false`. Set to `true` if you want
+    ///   to override the requirement for unique summary field names. Repeated
+    ///   names will be adjusted by appending "(1)" or similar after the
+    ///   field name.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/add-summary-fields
+    ///
+    pub async fn add_summary_fields_with_params(
+        &self,
+        sheet_id: u64,
+        fields: impl Into<Vec<SummaryField>>,
+        rename_if_conflict: impl Into<Option<bool>>,
+    ) -> Result<RowResult<SummaryField>> {
+        self.add_or_update_summary_fields(Method::POST, sheet_id, fields, rename_if_conflict.into())
+            .await
+    }
+
+    /// **Update Summary Fields** - Updates the summary fields for the
+    /// specified sheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to update the summary fields in.
+    /// * `fields` - An array (list) of summary fields with the updated values.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/update-summary-fields
+    ///
+    pub async fn update_summary_fields(
+        &self,
+        sheet_id: u64,
+        fields: impl Into<Vec<SummaryField>>,
+    ) -> Result<RowResult<SummaryField>> {
+        self.update_summary_fields_with_params(sheet_id, fields, None)
+            .await
+    }
+
+    /// **Update Summary Fields** - Updates the summary fields for the
+    /// specified sheet, with included _query parameters_.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to update the summary fields in.
+    /// * `fields` - An array (list) of summary fields with the updated values.
+    /// * `rename_if_conflict` - Default: `false`. Set to `true` if you want
+    ///   to override the requirement for unique summary field names.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/update-summary-fields
+    ///
+    pub async fn update_summary_fields_with_params(
+        &self,
+        sheet_id: u64,
+        fields: impl Into<Vec<SummaryField>>,
+        rename_if_conflict: impl Into<Option<bool>>,
+    ) -> Result<RowResult<SummaryField>> {
+        self.add_or_update_summary_fields(Method::PUT, sheet_id, fields, rename_if_conflict.into())
+            .await
+    }
+
+    /// Internal method to *add* or *update* summary fields in a sheet.
+    pub(crate) async fn add_or_update_summary_fields(
+        &self,
+        method: hyper::Method,
+        sheet_id: u64,
+        fields: impl Into<Vec<SummaryField>>,
+        rename_if_conflict: Option<bool>,
+    ) -> Result<RowResult<SummaryField>> {
+        // The endpoint to ADD or UPDATE summary fields is the same.
+        let mut url = format!(
+            "{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "summary/fields"
+        );
+
+        ParamBuilder::new(&mut url)
+            .with_value("renameIfConflict", rename_if_conflict)
+            .build();
+
+        debug!("URL: {}", url);
+
+        let data = serde_json::to_vec(&fields.into())?;
+
+        let req = Request::builder()
+            .method(method)
+            .uri(&url)
+            .body(Body::from(data))?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let result = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(result)
+    }
+
+    /// **Delete Summary Fields** - Deletes summary fields from the specified
+    /// sheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to delete the summary fields from.
+    /// * `field_ids` - An array (list) containing the IDs of the summary
+    ///   fields to delete from the smartsheet.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/delete-summary-fields
+    ///
+    pub async fn delete_summary_fields<const N: usize>(
+        &self,
+        sheet_id: u64,
+        field_ids: impl Into<[u64; N]>,
+    ) -> Result<RowResult<u64>> {
+        self.delete_summary_fields_with_params(sheet_id, field_ids, None)
+            .await
+    }
+
+    /// **Delete Summary Fields** - Deletes summary fields from the specified
+    /// sheet, with included _query parameters_.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to delete the summary fields from.
+    /// * `field_ids` - An array (list) containing the IDs of the summary
+    ///   fields to delete from the smartsheet.
+    /// * `ignore_summary_fields_not_found` - Default: `false`. If set to
+    ///   `false` and any of the specified field IDs are not found, no fields
+    ///   are deleted, and the "not found" error is returned.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/delete-summary-fields
+    ///
+    pub async fn delete_summary_fields_with_params<const N: usize>(
+        &self,
+        sheet_id: u64,
+        field_ids: impl Into<[u64; N]>,
+        ignore_summary_fields_not_found: impl Into<Option<bool>>,
+    ) -> Result<RowResult<u64>> {
+        let mut url = format!(
+            "{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "summary/fields"
+        );
+
+        ParamBuilder::new(&mut url)
+            .with_array("ids", field_ids.into())
+            .with_value(
+                "ignoreSummaryFieldsNotFound",
+                ignore_summary_fields_not_found.into(),
+            )
+            .build();
+
+        debug!("URL: {}", url);
+
+        let req = Request::delete(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let result = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(result)
+    }
+
+    /// **List Cross-sheet References** - Lists all cross-sheet references
+    /// for the sheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the cross-sheet references for.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/list-cross-sheet-references
+    ///
+    pub async fn list_cross_sheet_references(
+        &self,
+        sheet_id: u64,
+    ) -> Result<IndexResult<CrossSheetReference>> {
+        self.list_cross_sheet_references_with_params(sheet_id, None)
+            .await
+    }
+
+    /// **List Cross-sheet References** - Lists all cross-sheet references
+    /// for the sheet, with included _query parameters_.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the cross-sheet references for.
+    /// * `include_all` - If true, include all results (i.e. do not paginate).
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/list-cross-sheet-references
+    ///
+    pub async fn list_cross_sheet_references_with_params(
+        &self,
+        sheet_id: u64,
+        include_all: impl Into<Option<bool>>,
+    ) -> Result<IndexResult<CrossSheetReference>> {
+        let mut url = format!(
+            "{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "crosssheetreferences"
+        );
+
+        ParamBuilder::new(&mut url)
+            .with_value("includeAll", include_all.into())
+            .build();
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let references = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(references)
+    }
+
+    /// **Get Cross-sheet Reference** - Gets the cross-sheet reference
+    /// specified in the URL.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the cross-sheet reference for.
+    /// * `reference_id` - The Cross-sheet Reference Id to retrieve the data for.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/get-cross-sheet-reference
+    ///
+    pub async fn get_cross_sheet_reference(
+        &self,
+        sheet_id: u64,
+        reference_id: u64,
+    ) -> Result<CrossSheetReference> {
+        let url = format!(
+            "{}/{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "crosssheetreferences", reference_id
+        );
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let reference = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(reference)
+    }
+
+    /// **Create Cross-sheet Reference** - Adds a cross-sheet reference
+    /// between two sheets and defines the data range for formulas. Each
+    /// distinct data range requires a new cross-sheet reference.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet which will use the reference in formulas.
+    /// * `reference` - The cross-sheet reference to create, which defines
+    ///   the source sheet and the range of cells to reference.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/create-cross-sheet-reference
+    ///
+    pub async fn create_cross_sheet_reference(
+        &self,
+        sheet_id: u64,
+        reference: &CrossSheetReference,
+    ) -> Result<ItemResult<CrossSheetReference>> {
+        let url = format!(
+            "{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "crosssheetreferences"
+        );
+
+        debug!("URL: {}", url);
+
+        let data = serde_json::to_vec(reference)?;
+
+        let req = Request::post(&url).body(Body::from(data))?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let result = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(result)
+    }
+
+    /// **Create Update Request** - Creates an update request for the
+    /// specified rows within the sheet. An email notification (containing a
+    /// link to the update request) is sent to the specified recipients
+    /// according to the specified schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to create the update request for.
+    /// * `update_request` - The update request to create, which includes
+    ///   the rows, columns, recipients, and schedule of the request.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/updaterequests-create
+    ///
+    pub async fn create_update_request(
+        &self,
+        sheet_id: u64,
+        update_request: &UpdateRequest,
+    ) -> Result<ItemResult<UpdateRequest>> {
+        let url = format!(
+            "{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "updaterequests"
+        );
+
+        debug!("URL: {}", url);
+
+        let data = serde_json::to_vec(update_request)?;
+
+        let req = Request::post(&url).body(Body::from(data))?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let result = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(result)
+    }
+
+    /// **List Update Requests** - Gets a summarized list of all update
+    /// requests that have future schedules associated with the specified
+    /// sheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the update requests for.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/updaterequests-list
+    ///
+    pub async fn list_update_requests(&self, sheet_id: u64) -> Result<IndexResult<UpdateRequest>> {
+        self.list_update_requests_with_params(sheet_id, None).await
+    }
+
+    /// **List Update Requests** - Gets a summarized list of all update
+    /// requests that have future schedules associated with the specified
+    /// sheet, with included _query parameters_.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the update requests for.
+    /// * `include_all` - If true, include all results (i.e. do not paginate).
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/updaterequests-list
+    ///
+    pub async fn list_update_requests_with_params(
+        &self,
+        sheet_id: u64,
+        include_all: impl Into<Option<bool>>,
+    ) -> Result<IndexResult<UpdateRequest>> {
+        let mut url = format!(
+            "{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "updaterequests"
+        );
+
+        ParamBuilder::new(&mut url)
+            .with_value("includeAll", include_all.into())
+            .build();
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let update_requests = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(update_requests)
+    }
+
+    /// **Get Update Request** - Gets the specified update request for the
+    /// sheet that has a future schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the update request for.
+    /// * `update_request_id` - The Update Request Id to retrieve the data for.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/updaterequests-get
+    ///
+    pub async fn get_update_request(
+        &self,
+        sheet_id: u64,
+        update_request_id: u64,
+    ) -> Result<UpdateRequest> {
+        let url = format!(
+            "{}/{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "updaterequests", update_request_id
+        );
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let update_request = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(update_request)
+    }
+
+    /// **Delete Update Request** - Terminates the future scheduled delivery
+    /// of the update request specified.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to delete the update request from.
+    /// * `update_request_id` - The Update Request Id to delete.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/updaterequests-delete
+    ///
+    pub async fn delete_update_request(
+        &self,
+        sheet_id: u64,
+        update_request_id: u64,
+    ) -> Result<EmptyResult> {
+        let url = format!(
+            "{}/{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "updaterequests", update_request_id
+        );
+
+        self.delete_item(url).await
+    }
+
+    /// **List Sent Update Requests** - Gets a summarized list of all sent
+    /// update requests on the sheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the sent update requests for.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/sentupdaterequests-list
+    ///
+    pub async fn list_sent_update_requests(
+        &self,
+        sheet_id: u64,
+    ) -> Result<IndexResult<SentUpdateRequest>> {
+        self.list_sent_update_requests_with_params(sheet_id, None)
+            .await
+    }
+
+    /// **List Sent Update Requests** - Gets a summarized list of all sent
+    /// update requests on the sheet, with included _query parameters_.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the sent update requests for.
+    /// * `include_all` - If true, include all results (i.e. do not paginate).
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/sentupdaterequests-list
+    ///
+    pub async fn list_sent_update_requests_with_params(
+        &self,
+        sheet_id: u64,
+        include_all: impl Into<Option<bool>>,
+    ) -> Result<IndexResult<SentUpdateRequest>> {
+        let mut url = format!(
+            "{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "sentupdaterequests"
+        );
+
+        ParamBuilder::new(&mut url)
+            .with_value("includeAll", include_all.into())
+            .build();
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let sent_update_requests = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(sent_update_requests)
+    }
+
+    /// **Get Sent Update Request** - Gets the specified sent update request
+    /// on the sheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the sent update request for.
+    /// * `sent_update_request_id` - The Sent Update Request Id to retrieve
+    ///   the data for.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/sentupdaterequest-get
+    ///
+    pub async fn get_sent_update_request(
+        &self,
+        sheet_id: u64,
+        sent_update_request_id: u64,
+    ) -> Result<SentUpdateRequest> {
+        let url = format!(
+            "{}/{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "sentupdaterequests", sent_update_request_id
+        );
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let sent_update_request = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(sent_update_request)
+    }
+
+    /// **List Automation Rules** - Returns all automation rules associated
+    /// with the specified sheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the automation rules for.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/automationrules-list
+    ///
+    pub async fn list_automation_rules(
+        &self,
+        sheet_id: u64,
+    ) -> Result<IndexResult<AutomationRule>> {
+        self.list_automation_rules_with_params(sheet_id, None).await
+    }
+
+    /// **List Automation Rules** - Returns all automation rules associated
+    /// with the specified sheet, with included _query parameters_.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the automation rules for.
+    /// * `include_all` - If true, include all results (i.e. do not paginate).
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/automationrules-list
+    ///
+    pub async fn list_automation_rules_with_params(
+        &self,
+        sheet_id: u64,
+        include_all: impl Into<Option<bool>>,
+    ) -> Result<IndexResult<AutomationRule>> {
+        let mut url = format!(
+            "{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "automationrules"
+        );
+
+        ParamBuilder::new(&mut url)
+            .with_value("includeAll", include_all.into())
+            .build();
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let rules = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(rules)
+    }
+
+    /// **Get Automation Rule** - Returns the specified automation rule,
+    /// including any action values.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the automation rule for.
+    /// * `rule_id` - The Automation Rule Id to retrieve the data for.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/automationrule-get
+    ///
+    pub async fn get_automation_rule(&self, sheet_id: u64, rule_id: u64) -> Result<AutomationRule> {
+        let url = format!(
+            "{}/{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "automationrules", rule_id
+        );
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let rule = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(rule)
+    }
+
+    /// **Update Automation Rule** - Updates an existing automation rule.
+    ///
+    /// When sending an `AutomationRule`, you must always specify
+    /// `action.type` and it must match the existing rule type; the rule can
+    /// be created with `AutomationRule::new` for this.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet which contains the automation rule.
+    /// * `rule_id` - The Automation Rule Id to update.
+    /// * `rule` - The automation rule with the updated values.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/automationrule-update
+    ///
+    pub async fn update_automation_rule(
+        &self,
+        sheet_id: u64,
+        rule_id: u64,
+        rule: &AutomationRule,
+    ) -> Result<ItemResult<AutomationRule>> {
+        let url = format!(
+            "{}/{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "automationrules", rule_id
+        );
+
+        debug!("URL: {}", url);
+
+        let data = serde_json::to_vec(rule)?;
+
+        let req = Request::put(&url).body(Body::from(data))?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let result = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(result)
+    }
+
+    /// **Delete Automation Rule** - Deletes an automation rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet which contains the automation rule.
+    /// * `rule_id` - The Automation Rule Id to delete.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/automationrule-delete
+    ///
+    pub async fn delete_automation_rule(&self, sheet_id: u64, rule_id: u64) -> Result<EmptyResult> {
+        let url = format!(
+            "{}/{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "automationrules", rule_id
+        );
+
+        self.delete_item(url).await
+    }
+
+    /// **List Proofs** - Gets a list of all proofs for a given sheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the proofs for.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/proofs-getAllProofs
+    ///
+    pub async fn list_proofs(&self, sheet_id: u64) -> Result<IndexResult<Proof>> {
+        self.list_proofs_with_params(sheet_id, None).await
+    }
+
+    /// **List Proofs** - Gets a list of all proofs for a given sheet, with
+    /// included _query parameters_.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the proofs for.
+    /// * `include_all` - If true, include all results (i.e. do not paginate).
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/proofs-getAllProofs
+    ///
+    pub async fn list_proofs_with_params(
+        &self,
+        sheet_id: u64,
+        include_all: impl Into<Option<bool>>,
+    ) -> Result<IndexResult<Proof>> {
+        let mut url = format!("{}/{}/{}/{}", self.endpoint, "sheets", sheet_id, "proofs");
+
+        ParamBuilder::new(&mut url)
+            .with_value("includeAll", include_all.into())
+            .build();
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let proofs = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(proofs)
+    }
+
+    /// **Get Proof** - Gets the proof specified by *id*.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the proof for.
+    /// * `proof_id` - The Proof Id to retrieve the data for.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/proofs-get
+    ///
+    pub async fn get_proof(&self, sheet_id: u64, proof_id: u64) -> Result<Proof> {
+        self.get_proof_with_params(sheet_id, proof_id, None).await
+    }
+
+    /// **Get Proof** - Gets the proof specified by *id*, with included
+    /// _query parameters_.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the proof for.
+    /// * `proof_id` - The Proof Id to retrieve the data for.
+    /// * `include` - A comma-separated list of elements to include in the response.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/proofs-get
+    ///
+    pub async fn get_proof_with_params(
+        &self,
+        sheet_id: u64,
+        proof_id: u64,
+        include: impl Into<Option<Vec<ProofIncludeFlags>>>,
+    ) -> Result<Proof> {
+        let mut url = format!(
+            "{}/{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "proofs", proof_id
+        );
+
+        ParamBuilder::new(&mut url)
+            .with_comma_separated_values("include", include.into())
+            .build();
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let proof = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(proof)
+    }
+
+    /// **Create Proof** - Creates a proof on a row, by uploading a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet which contains the row.
+    /// * `row_id` - The Row Id to create the proof on.
+    /// * `file_name` - The name of the file to upload.
+    /// * `content_type` - The MIME type of the file, for example `image/png`.
+    /// * `data` - The contents of the file.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/proofs-create
+    ///
+    pub async fn create_proof(
+        &self,
+        sheet_id: u64,
+        row_id: u64,
+        file_name: &str,
+        content_type: &str,
+        data: impl Into<Vec<u8>>,
+    ) -> Result<ItemResult<Proof>> {
+        let url = format!(
+            "{}/{}/{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "rows", row_id, "proofs"
+        );
+
+        self.upload_file(url, file_name, content_type, data).await
+    }
+
+    /// **Update Proof Status** - Sets the proof status as either complete
+    /// or incomplete.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet which contains the proof.
+    /// * `proof_id` - The Proof Id to update.
+    /// * `is_completed` - Whether the proof is to be marked as complete.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/proofs-update
+    ///
+    pub async fn update_proof_status(
+        &self,
+        sheet_id: u64,
+        proof_id: u64,
+        is_completed: bool,
+    ) -> Result<ItemResult<Proof>> {
+        let url = format!(
+            "{}/{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "proofs", proof_id
+        );
+
+        debug!("URL: {}", url);
+
+        let data = serde_json::to_vec(&serde_json::json!({ "isCompleted": is_completed }))?;
+
+        let req = Request::put(&url).body(Body::from(data))?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let result = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(result)
+    }
+
+    /// **Delete Proof** - Deletes the proof including all versions. The
+    /// proof Id must be for the original version.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet which contains the proof.
+    /// * `proof_id` - The Proof Id to delete.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/proofs-delete
+    ///
+    pub async fn delete_proof(&self, sheet_id: u64, proof_id: u64) -> Result<EmptyResult> {
+        let url = format!(
+            "{}/{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "proofs", proof_id
+        );
+
+        self.delete_item(url).await
+    }
+
+    /// **List Proof Versions** - Gets a list of all versions of the given
+    /// proof.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet which contains the proof.
+    /// * `proof_id` - The Proof Id to retrieve the versions for.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/proofs-getVersions
+    ///
+    pub async fn list_proof_versions(
+        &self,
+        sheet_id: u64,
+        proof_id: u64,
+    ) -> Result<IndexResult<Proof>> {
+        let url = format!(
+            "{}/{}/{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "proofs", proof_id, "versions"
+        );
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let versions = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(versions)
+    }
+
+    /// **Create Proof Version** - Creates a new version of the proof, by
+    /// uploading a file. The proof Id must be for the original version.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet which contains the proof.
+    /// * `proof_id` - The Proof Id to create a new version for.
+    /// * `file_name` - The name of the file to upload.
+    /// * `content_type` - The MIME type of the file, for example `image/png`.
+    /// * `data` - The contents of the file.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/proofs-createVersion
+    ///
+    pub async fn create_proof_version(
+        &self,
+        sheet_id: u64,
+        proof_id: u64,
+        file_name: &str,
+        content_type: &str,
+        data: impl Into<Vec<u8>>,
+    ) -> Result<ItemResult<Proof>> {
+        let url = format!(
+            "{}/{}/{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "proofs", proof_id, "versions"
+        );
+
+        self.upload_file(url, file_name, content_type, data).await
+    }
+
+    /// **Delete Proof Version** - Deletes a proof version. The proof Id must
+    /// be a current version proof Id.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet which contains the proof.
+    /// * `proof_id` - The Proof Id of the version to delete.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/proofs-deleteVersion
+    ///
+    pub async fn delete_proof_version(&self, sheet_id: u64, proof_id: u64) -> Result<EmptyResult> {
+        let url = format!(
+            "{}/{}/{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "proofs", proof_id, "versions"
+        );
+
+        self.delete_item(url).await
+    }
+
+    /// **Create Proof Request** - Creates a proof request, which asks the
+    /// specified recipients to review the proof.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet which contains the proof.
+    /// * `proof_id` - The Proof Id to request a review for.
+    /// * `request` - The proof request, which includes the recipients and
+    ///   the message to send.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/proofs-createProofRequests
+    ///
+    pub async fn create_proof_request(
+        &self,
+        sheet_id: u64,
+        proof_id: u64,
+        request: &ProofRequest,
+    ) -> Result<ItemResult<ProofRequest>> {
+        let url = format!(
+            "{}/{}/{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "proofs", proof_id, "requests"
+        );
+
+        debug!("URL: {}", url);
+
+        let data = serde_json::to_vec(request)?;
+
+        let req = Request::post(&url).body(Body::from(data))?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let result = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(result)
+    }
+
+    /// **Delete Proof Requests** - Deletes all proof requests in a proof.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet which contains the proof.
+    /// * `proof_id` - The Proof Id to delete the requests for.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/proofs-deleteProofRequests
+    ///
+    pub async fn delete_proof_requests(&self, sheet_id: u64, proof_id: u64) -> Result<EmptyResult> {
+        let url = format!(
+            "{}/{}/{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "proofs", proof_id, "requests"
+        );
+
+        self.delete_item(url).await
+    }
+
+    /// **List Proof Request Actions** - Gets a summarized list of all request
+    /// actions - such as approvals and rejections - on the proof.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet which contains the proof.
+    /// * `proof_id` - The Proof Id to retrieve the request actions for.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/proofs-getRequestActions
+    ///
+    pub async fn list_proof_request_actions(
+        &self,
+        sheet_id: u64,
+        proof_id: u64,
+    ) -> Result<IndexResult<ProofRequestAction>> {
+        let url = format!(
+            "{}/{}/{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "proofs", proof_id, "requestactions"
+        );
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let actions = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(actions)
+    }
+
+    /// **Get Sheet Publish Status** - Gets the sheet's publish status,
+    /// including the URLs of any enabled publishings.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the publish status for.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/get-sheetPublish
+    ///
+    pub async fn get_sheet_publish_status(&self, sheet_id: u64) -> Result<SheetPublish> {
+        let url = format!("{}/{}/{}/{}", self.endpoint, "sheets", sheet_id, "publish");
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let status = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(status)
+    }
+
+    /// **Set Sheet Publish Status** - Sets the publish status of the sheet,
+    /// and returns the new status, including the URLs of any enabled
+    /// publishings.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to set the publish status for.
+    /// * `status` - The `SheetPublish` status to set.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/set-sheetPublish
+    ///
+    pub async fn set_sheet_publish_status(
+        &self,
+        sheet_id: u64,
+        status: &SheetPublish,
+    ) -> Result<ItemResult<SheetPublish>> {
+        let url = format!("{}/{}/{}/{}", self.endpoint, "sheets", sheet_id, "publish");
+
+        debug!("URL: {}", url);
+
+        let data = serde_json::to_vec(status)?;
+
+        let req = Request::put(&url).body(Body::from(data))?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let result = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(result)
+    }
+
+    /// **Add Image to Cell** - Uploads an image to the specified cell
+    /// within a sheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet which contains the cell.
+    /// * `row_id` - The Row Id of the cell.
+    /// * `column_id` - The Column Id of the cell.
+    /// * `file_name` - The name of the image file to upload.
+    /// * `content_type` - The MIME type of the image, for example `image/png`.
+    /// * `data` - The contents of the image file.
+    /// * `alt_text` - Optional alternate text for the image; defaults to
+    ///   the file name.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/addImageToCell
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub async fn add_image_to_cell(
+        &self,
+        sheet_id: u64,
+        row_id: u64,
+        column_id: u64,
+        file_name: &str,
+        content_type: &str,
+        data: impl Into<Vec<u8>>,
+        alt_text: impl Into<Option<&str>>,
+    ) -> Result<ItemResult<Row>> {
+        let mut url = format!(
+            "{}/{}/{}/{}/{}/{}/{}/{}",
+            self.endpoint, "sheets", sheet_id, "rows", row_id, "columns", column_id, "cellimages"
+        );
+
+        ParamBuilder::new(&mut url)
+            .with_value("altText", alt_text.into().map(encode_uri_component))
+            .build();
+
+        self.upload_file(url, file_name, content_type, data).await
+    }
+
+    /// **List Image URLs** - Resolves a list of cell images into temporary
+    /// URLs, which can be used to download the images.
+    ///
+    /// The `width` and `height` of each `ImageUrl` can optionally be set, to
+    /// request the image be resized; otherwise, the original size is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_urls` - A list of `ImageUrl` objects, one for each image.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/listImageUrls
+    ///
+    pub async fn get_image_urls(
+        &self,
+        image_urls: impl Into<Vec<ImageUrl>>,
+    ) -> Result<ImageUrlMap> {
+        let url = format!("{}/{}", self.endpoint, "imageurls");
+
+        debug!("URL: {}", url);
+
+        let data = serde_json::to_vec(&image_urls.into())?;
+
+        let req = Request::post(&url).body(Body::from(data))?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let url_map = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(url_map)
+    }
+
+    /// **List Image URLs** - Resolves every cell image in a `Sheet` into a
+    /// temporary URL, in a single call.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet` - The `Sheet` which contains the images.
+    /// * `width` - Optional width (in pixels) to resize each image to.
+    /// * `height` - Optional height (in pixels) to resize each image to.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/listImageUrls
+    ///
+    pub async fn get_sheet_image_urls(
+        &self,
+        sheet: &Sheet,
+        width: impl Into<Option<u64>>,
+        height: impl Into<Option<u64>>,
+    ) -> Result<ImageUrlMap> {
+        let (width, height) = (width.into(), height.into());
+
+        let image_urls: Vec<ImageUrl> = sheet
+            .images()
+            .into_iter()
+            .map(|image| ImageUrl::from(image).width(width).height(height))
+            .collect();
+
+        if image_urls.is_empty() {
+            return Ok(ImageUrlMap::default());
+        }
+
+        self.get_image_urls(image_urls).await
+    }
+
+    /// **List Public Templates** - Gets a list of public templates that the
+    /// user has access to.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/templates-listPublic
+    ///
+    pub async fn list_public_templates(&self) -> Result<IndexResult<Template>> {
+        self.list_public_templates_with_params(None).await
+    }
+
+    /// **List Public Templates** - Gets a list of public templates that the
+    /// user has access to, with included _query parameters_.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_all` - If true, include all results (i.e. do not paginate).
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/templates-listPublic
+    ///
+    pub async fn list_public_templates_with_params(
+        &self,
+        include_all: impl Into<Option<bool>>,
+    ) -> Result<IndexResult<Template>> {
+        let mut url = format!("{}/{}", self.endpoint, "templates/public");
+
+        ParamBuilder::new(&mut url)
+            .with_value("includeAll", include_all.into())
+            .build();
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let templates = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(templates)
+    }
+
+    /// **List User-Created Templates** - Gets a list of user-created
+    /// templates that the user has access to.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/templates-list
+    ///
+    pub async fn list_user_templates(&self) -> Result<IndexResult<Template>> {
+        self.list_user_templates_with_params(None).await
+    }
+
+    /// **List User-Created Templates** - Gets a list of user-created
+    /// templates that the user has access to, with included _query
+    /// parameters_.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_all` - If true, include all results (i.e. do not paginate).
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/templates-list
+    ///
+    pub async fn list_user_templates_with_params(
+        &self,
+        include_all: impl Into<Option<bool>>,
+    ) -> Result<IndexResult<Template>> {
+        let mut url = format!("{}/{}", self.endpoint, "templates");
+
+        ParamBuilder::new(&mut url)
+            .with_value("includeAll", include_all.into())
+            .build();
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let templates = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(templates)
+    }
+
+    /// **Create Sheet from Template** - Creates a sheet in the user's
+    /// *Sheets* folder, from the specified template.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet` - The name of the new sheet, and the Template Id to
+    ///   create it from.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/create-sheet-in-sheets-folder
+    ///
+    pub async fn create_sheet_from_template(
+        &self,
+        sheet: &SheetFromTemplate,
+    ) -> Result<ItemResult<Sheet>> {
+        self.create_sheet_from_template_with_params(sheet, None)
+            .await
+    }
+
+    /// **Create Sheet from Template** - Creates a sheet in the user's
+    /// *Sheets* folder, from the specified template, with included _query
+    /// parameters_.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet` - The name of the new sheet, and the Template Id to
+    ///   create it from.
+    /// * `include` - A list of elements to copy from the template.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/create-sheet-in-sheets-folder
+    ///
+    pub async fn create_sheet_from_template_with_params(
+        &self,
+        sheet: &SheetFromTemplate,
+        include: impl Into<Option<Vec<SheetFromTemplateIncludeFlags>>>,
+    ) -> Result<ItemResult<Sheet>> {
+        let mut url = format!("{}/{}", self.endpoint, "sheets");
+
+        ParamBuilder::new(&mut url)
+            .with_comma_separated_values("include", include.into())
+            .build();
+
+        debug!("URL: {}", url);
+
+        let data = serde_json::to_vec(sheet)?;
+
+        let req = Request::post(&url).body(Body::from(data))?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let result = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(result)
+    }
+
+    /// **List Favorites** - Gets a list of all of the user's favorite
+    /// items.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/getFavorites
+    ///
+    pub async fn list_favorites(&self) -> Result<IndexResult<Favorite>> {
+        self.list_favorites_with_params(None).await
+    }
+
+    /// **List Favorites** - Gets a list of all of the user's favorite
+    /// items, with included _query parameters_.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_all` - If true, include all results (i.e. do not paginate).
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/getFavorites
+    ///
+    pub async fn list_favorites_with_params(
+        &self,
+        include_all: impl Into<Option<bool>>,
+    ) -> Result<IndexResult<Favorite>> {
+        let mut url = format!("{}/{}", self.endpoint, "favorites");
+
+        ParamBuilder::new(&mut url)
+            .with_value("includeAll", include_all.into())
+            .build();
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let favorites = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(favorites)
+    }
+
+    /// **Add Favorites** - Adds one or more items to the user's list of
+    /// favorite items.
+    ///
+    /// # Arguments
+    ///
+    /// * `favorites` - One or more `Favorite` objects to add.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/addFavorites
+    ///
+    pub async fn add_favorites(
+        &self,
+        favorites: impl Into<Vec<Favorite>>,
+    ) -> Result<RowResult<Favorite>> {
+        let url = format!("{}/{}", self.endpoint, "favorites");
+
+        debug!("URL: {}", url);
+
+        let data = serde_json::to_vec(&favorites.into())?;
+
+        let req = Request::post(&url).body(Body::from(data))?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let result = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(result)
+    }
+
+    /// **Delete Favorite** - Removes a single item from the user's list of
+    /// favorite items.
+    ///
+    /// # Arguments
+    ///
+    /// * `favorite_type` - The type of the favorited item.
+    /// * `object_id` - The Id of the favorited item.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/removeFavorite
+    ///
+    pub async fn remove_favorite(
+        &self,
+        favorite_type: FavoriteType,
+        object_id: u64,
+    ) -> Result<EmptyResult> {
+        let url = format!(
+            "{}/{}/{}/{}",
+            self.endpoint, "favorites", favorite_type, object_id
+        );
+
+        self.delete_item(url).await
+    }
+
+    /// **Delete Multiple Favorites** - Removes multiple items of the same
+    /// type from the user's list of favorite items.
+    ///
+    /// # Arguments
+    ///
+    /// * `favorite_type` - The type of the favorited items.
+    /// * `object_ids` - The Ids of the favorited items.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/removeFavoritesOfType
+    ///
+    pub async fn remove_favorites<const N: usize>(
+        &self,
+        favorite_type: FavoriteType,
+        object_ids: impl Into<[u64; N]>,
+    ) -> Result<EmptyResult> {
+        let mut url = format!("{}/{}/{}", self.endpoint, "favorites", favorite_type);
+
+        ParamBuilder::new(&mut url)
+            .with_array("objectIds", object_ids.into())
+            .build();
+
+        self.delete_item(url).await
+    }
+
+    /// **Get Server Info** - Gets application constants, such as the
+    /// supported locales and time zones, and the format tables.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/serverinfo-get
+    ///
+    pub async fn get_server_info(&self) -> Result<ServerInfo> {
+        let url = format!("{}/{}", self.endpoint, "serverinfo");
+
+        debug!("URL: {}", url);
+
+        let req = Request::get(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let info = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(info)
+    }
+
+    /// Internal method to send a request to the Smartsheet API, which sets
+    /// the `AUTHORIZATION` header with a token from the `TokenProvider`.
+    ///
+    /// If the request fails due to an expired access token, and the
+    /// `TokenProvider` is able to refresh it, the request is retried once.
+    pub(crate) async fn send(&self, req: Request<Body>) -> Result<Response<Body>> {
+        let (parts, body) = req.into_parts();
+        let body = hyper::body::to_bytes(body).await?;
+        let url = parts.uri.to_string();
+        let span = RequestSpan::new(&parts.method, &parts.uri);
+
+        let mut attempt = 1;
+
+        loop {
+            let token = self.tokens.access_token().await?;
+
+            let mut req = Request::new(Body::from(body.clone()));
+            *req.method_mut() = parts.method.clone();
+            *req.uri_mut() = parts.uri.clone();
+            *req.headers_mut() = parts.headers.clone();
+            for (name, value) in self.headers.iter() {
+                req.headers_mut().insert(name, value.clone());
+            }
+            req.headers_mut()
+                .insert(AUTHORIZATION, HeaderValue::from_str(&auth_token(&token))?);
+            if let Some(encoding) = accept_encoding() {
+                req.headers_mut().entry(ACCEPT_ENCODING).or_insert(encoding);
+            }
+            // A recording `Cassette` sends the request with this client
+            #[cfg(feature = "testing")]
+            req.extensions_mut().insert(self.client.clone());
+
+            let mut cached = None;
+            for middleware in self.middleware.iter() {
+                cached = middleware.before_request(&mut req).await?;
+                if cached.is_some() {
+                    break;
+                }
+            }
+
+            // Wait for the rate limiter before the request is timed, so that
+            // the time spent waiting isn't reported as network latency
+            if let (None, Some(limiter)) = (&cached, &self.limiter) {
+                let weight = limiter.weight(req.method(), req.uri().path());
+                limiter.acquire(weight).await;
+            }
+
+            let start = Instant::now();
+
+            let res = match cached {
+                Some(res) => res,
+                None => span.attempt(attempt, self.client.request(req)).await?,
+            };
+
+            let mut res = decompress(res).await?;
+
+            debug!("Request: {:?}", start.elapsed());
+
+            span.record_response(res.status(), start.elapsed());
+
+            for middleware in self.middleware.iter().rev() {
+                middleware.after_response(&res).await?;
+            }
+
+            if let Some(slot) = &self.meta {
+                *slot.lock().unwrap() = Some(ResponseMeta {
+                    method: parts.method.clone(),
+                    url: url.to_owned(),
+                    status: res.status(),
+                    headers: res.headers().clone(),
+                    elapsed: start.elapsed(),
+                });
+            }
+
+            if attempt == 1 && res.status() == StatusCode::UNAUTHORIZED {
+                let data = hyper::body::to_bytes(res.body_mut()).await?;
+
+                if is_rejected_token(&data) && self.tokens.refresh(&token).await? {
+                    warn!("Access token was rejected, retrying with a new token");
+                    attempt += 1;
+                    continue;
+                }
+
+                *res.body_mut() = Body::from(data);
+            }
+
+            if let Err(e) = raise_for_status(url, &mut res).await {
+                if let Some(e) = e.downcast_ref::<RequestError>() {
+                    span.record_error(e);
+                }
+                return Err(e);
+            }
+
+            span.attach(&mut res);
+
+            return Ok(res);
+        }
+    }
+
+    /// Internal method to upload a file to the specified `url`, using a
+    /// [simple upload] - that is, the body of the request is the raw
+    /// contents of the file.
+    ///
+    /// [simple upload]: https://smartsheet.redoc.ly/#section/Attachments/Simple-Uploads
+    pub(crate) async fn upload_file<T: DeserializeOwned>(
+        &self,
+        url: String,
+        file_name: &str,
+        content_type: &str,
+        data: impl Into<Vec<u8>>,
+    ) -> Result<T> {
+        debug!("URL: {}", url);
+
+        let data = data.into();
+        let disposition = format!(
+            "attachment; filename=\"{}\"",
+            file_name.replace('"', "\\\"")
+        );
+
+        let req = Request::post(&url)
+            .header(CONTENT_TYPE, content_type)
+            .header(CONTENT_LENGTH, data.len())
+            .header(CONTENT_DISPOSITION, disposition)
+            .body(Body::from(data))?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let result = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(result)
+    }
+
+    /// Internal method to delete a *single* object at the specified `url`.
+    pub(crate) async fn delete_item(&self, url: String) -> Result<EmptyResult> {
+        debug!("URL: {}", url);
+
+        let req = Request::delete(&url).body(Body::empty())?;
+
+        let res = self.send(req).await?;
+
+        let start = Instant::now();
+
+        let result = into_struct_from_slice(res).await?;
+
+        debug!("Deserialize: {:?}", start.elapsed());
+
+        Ok(result)
+    }
+
+    /// **Get Sheet By Name** - Convenience function to retrieve a specified
+    /// sheet by name. Used for those times when you don't know the Sheet Id.
+    ///
+    /// This will internally call `list_sheets` and then filter the response
+    /// data by the sheet name. It returns the first matching name.
+    ///
+    /// Returns the sheet, including rows, and optionally populated with
+    /// discussion and attachment objects.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_name` - The name of the Smartsheet to filter results by.
+    ///
+    #[deprecated(
+        since = "0.2.0",
+        note = "please cache the sheet id and use `get_sheet` instead"
+    )]
+    pub async fn get_sheet_by_name(&self, sheet_name: &str) -> Result<Sheet> {
+        // Display a warning that the usage of this method is not recommended
+        warn!(
+            "{}",
+            "Calling `get_sheet_by_name()` is not recommended; it's \
+                preferable to cache the sheet ID and call \
+                `get_sheet()` instead."
+        );
+
+        // Get a fresh list of sheets
+        let result = self.list_sheets_with_params(None, Some(true), None).await?;
+
+        // Find the sheet by the provided name
+        match result
+            .data
+            .into_iter()
+            .find(|sheet| sheet.name == sheet_name)
+        {
+            Some(sheet) => Ok(sheet),
+            None => Err(Box::from(Error::new(
+                ErrorKind::NotFound,
+                format!("The provided sheet `{}` was not found", sheet_name),
+            ))),
+        }
+    }
+
+    /// **Get Column By Title** - Convenience function to retrieve a specified
+    /// column by title (name). Used for those times when you don't know the
+    /// Column Id.
+    ///
+    /// This will internally call `list_columns` and then filter the response
+    /// data by the column title. It returns the first matching name.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_id` - The Smartsheet to retrieve the column from.
+    /// * `column_title` - The name of the column to filter results by.
+    ///
+    ///
+    #[deprecated(
+        since = "0.2.0",
+        note = "please cache the column id and use `get_column` instead"
+    )]
+    pub async fn get_column_by_title(&self, sheet_id: u64, column_title: &str) -> Result<Column> {
+        // Display a warning that the usage of this method is not recommended
+        warn!(
+            "{}",
+            "Calling `get_column_by_title()` is not recommended; it's \
+                preferable to cache the column ID and call \
+                `get_column()` instead."
+        );
+
+        // Get a fresh list of columns
+        let result = self
+            .list_columns_with_params(sheet_id, None, None, Some(true))
+            .await?;
+
+        // Find the column by the provided name
+        match result
+            .data
+            .into_iter()
+            .find(|column| column.title == column_title)
+        {
+            Some(column) => Ok(column),
+            None => Err(Box::from(Error::new(
+                ErrorKind::NotFound,
+                format!("The provided column `{}` was not found", column_title),
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::oauth::{OAuthClient, OAuthToken};
+    use crate::test_utils::MockServer;
+
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_send_refreshes_expired_token() {
+        let server = MockServer::start(vec![
+            (
+                401,
+                r#"{"errorCode": 1003, "message": "Your Access Token has expired."}"#,
+            ),
+            (
+                200,
+                r#"{"access_token": "new-token", "refresh_token": "new-refresh", "expires_in": 3600}"#,
+            ),
+            (
+                200,
+                r#"{"pageNumber": 1, "totalCount": 0, "totalPages": 1, "data": []}"#,
+            ),
+        ]);
+
+        let provider = OAuthClient::new("my-client", "secret")
+            .with_token_url(server.url("/token"))
+            .into_provider(OAuthToken::new("old-token", "old-refresh"));
+
+        let endpoint = server.url("");
+        let smart = SmartsheetApi::from_endpoint_and_provider(&endpoint, provider);
+
+        let sheets = smart.list_sheets().await.unwrap();
+        assert_eq!(sheets.total_count, 0);
+
+        let requests = server.requests();
+        assert!(requests[0].contains("authorization: Bearer old-token"));
+        assert!(requests[1].starts_with("POST /token "));
+        assert!(requests[2].contains("authorization: Bearer new-token"));
+    }
+
+    #[tokio::test]
+    async fn test_send_rereads_invalid_file_token() {
+        use crate::auth::FileToken;
+        use async_trait::async_trait;
+
+        /// Rotates the token in the file once the old token is rejected.
+        struct RotateToken(std::path::PathBuf);
+
+        #[async_trait]
+        impl Middleware for RotateToken {
+            async fn after_response(&self, res: &Response<Body>) -> Result<()> {
+                if res.status() == StatusCode::UNAUTHORIZED {
+                    std::fs::write(&self.0, "new-token\n")?;
+                }
+                Ok(())
+            }
+        }
+
+        let server = MockServer::start(vec![
+            (
+                401,
+                r#"{"errorCode": 1002, "message": "Your Access Token is invalid."}"#,
+            ),
+            (
+                200,
+                r#"{"pageNumber": 1, "totalCount": 0, "totalPages": 1, "data": []}"#,
+            ),
+        ]);
+
+        let path = std::env::temp_dir().join(format!(
+            "smartsheet-rs-test-invalid-file-token-{}",
+            std::process::id()
+        ));
+        std::fs::write(&path, "old-token\n").unwrap();
+
+        let endpoint = server.url("");
+        let smart = SmartsheetApi::from_endpoint_and_provider(&endpoint, FileToken::new(&path))
+            .with_middleware(RotateToken(path.clone()));
+
+        let sheets = smart.list_sheets().await.unwrap();
+        assert_eq!(sheets.total_count, 0);
+
+        let requests = server.requests();
+        assert!(requests[0].contains("authorization: Bearer old-token"));
+        assert!(requests[1].contains("authorization: Bearer new-token"));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_export_sheet() {
+        let server = MockServer::start(vec![(200, "id,name\n1,Widget\n"), (200, "%PDF-1.4")]);
+
+        let endpoint = server.url("");
+        let smart = SmartsheetApi::from_endpoint_and_token(&endpoint, "my-token");
+
+        let csv = smart.export_sheet(123, ExportFormat::Csv).await.unwrap();
+        let csv = hyper::body::to_bytes(csv).await.unwrap();
+        assert_eq!(&csv[..], b"id,name\n1,Widget\n");
+
+        let pdf = smart
+            .export_sheet(123, ExportFormat::Pdf(PaperSize::A4))
+            .await
+            .unwrap();
+        let pdf = hyper::body::to_bytes(pdf).await.unwrap();
+        assert_eq!(&pdf[..], b"%PDF-1.4");
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("GET /sheets/123 "));
+        assert!(requests[0].contains("accept: text/csv\r\n"));
+        assert!(requests[1].starts_with("GET /sheets/123?paperSize=A4 "));
+        assert!(requests[1].contains("accept: application/pdf\r\n"));
+    }
+
+    #[tokio::test]
+    async fn test_export_report() {
+        let server = MockServer::start(vec![(200, "PK\u{3}\u{4}")]);
+
+        let endpoint = server.url("");
+        let smart = SmartsheetApi::from_endpoint_and_token(&endpoint, "my-token");
+
+        let excel = smart.export_report(456, ExportFormat::Excel).await.unwrap();
+        let excel = hyper::body::to_bytes(excel).await.unwrap();
+        assert_eq!(&excel[..], b"PK\x03\x04");
+
+        let pdf = smart.export_report(456, ExportFormat::Pdf(PaperSize::Letter));
+        assert!(pdf.await.is_err());
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /reports/456 "));
+        assert!(requests[0].contains("accept: application/vnd.ms-excel\r\n"));
+    }
+
+    #[tokio::test]
+    async fn test_send_with_assume_user_and_change_agent() {
+        let list = r#"{"pageNumber": 1, "totalCount": 0, "totalPages": 1, "data": []}"#;
+        let server = MockServer::start(vec![(200, list), (200, list)]);
+
+        let endpoint = server.url("");
+        let smart = SmartsheetApi::from_endpoint_and_token(&endpoint, "my-token")
+            .with_change_agent("my-integration")
+            .unwrap();
+
+        smart.as_user("x+1@y.com").list_sheets().await.unwrap();
+        smart.list_sheets().await.unwrap();
+
+        let requests = server.requests();
+        assert!(requests[0].contains("assume-user: x%2B1%40y.com\r\n"));
+        assert!(requests[0].contains("smartsheet-change-agent: my-integration\r\n"));
+        assert!(!requests[1].contains("assume-user"));
+        assert!(requests[1].contains("smartsheet-change-agent: my-integration\r\n"));
+    }
+
+    #[test]
+    fn test_client_is_send_sync_and_static() {
+        fn assert_shareable<T: Clone + Send + Sync + 'static>() {}
+
+        assert_shareable::<SmartsheetApi>();
+    }
+
+    #[tokio::test]
+    async fn test_with_meta() {
+        let server = MockServer::start(vec![(
+            200,
+            r#"{"pageNumber": 1, "totalCount": 2, "totalPages": 1, "data": []}"#,
+        )]);
+
+        let endpoint = server.url("");
+        let smart = SmartsheetApi::from_endpoint_and_token(&endpoint, "my-token");
+
+        let res = smart
+            .with_meta(|api| async move { api.list_sheets().await })
+            .await
+            .unwrap();
+
+        assert_eq!(res.data.total_count, 2);
+        assert_eq!(res.meta.method, Method::GET);
+        assert_eq!(res.meta.url, server.url("/sheets"));
+        assert_eq!(res.meta.status, StatusCode::OK);
+        assert_eq!(res.meta.header("content-type"), Some("application/json"));
+    }
+
+    #[tokio::test]
+    async fn test_with_meta_excludes_rate_limit_wait() {
+        let server = MockServer::start(vec![(
+            200,
+            r#"{"pageNumber": 1, "totalCount": 0, "totalPages": 1, "data": []}"#,
+        )]);
+
+        // Use up the budget, so that the next request waits for 500ms
+        let limiter = RateLimiter::new(120);
+        limiter.acquire(120).await;
+
+        let endpoint = server.url("");
+        let smart = SmartsheetApi::from_endpoint_and_token(&endpoint, "my-token")
+            .with_rate_limiter(limiter);
+
+        let start = Instant::now();
+        let res = smart
+            .with_meta(|api| async move { api.list_sheets().await })
+            .await
+            .unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(450));
+        assert!(res.meta.elapsed < Duration::from_millis(250));
+    }
+
+    #[tokio::test]
+    async fn test_attach_url_in_gov_region() {
+        let smart = SmartsheetApi::from_region_and_token(Region::Gov, "my-token");
+
+        let attachment = UrlAttachment::new(AttachmentType::Dropbox, "file", "https://a.b/c");
+        let err = smart
+            .attach_url_to_sheet(123, &attachment)
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Attachment type `Dropbox` is not allowed in region `GOV`"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_does_not_retry_static_token() {
+        let server = MockServer::start(vec![(
+            401,
+            r#"{"errorCode": 1003, "message": "Your Access Token has expired."}"#,
+        )]);
+
+        let endpoint = server.url("");
+        let smart = SmartsheetApi::from_endpoint_and_token(&endpoint, "my-token");
+
+        let err = smart.list_sheets().await.unwrap_err();
+        let err = err.downcast_ref::<RequestError>().unwrap();
+
+        assert_eq!(err.status, 401);
+        assert_eq!(err.error.as_ref().unwrap().error_code, 1003);
+        assert_eq!(server.requests().len(), 1);
     }
 }
@@ -1,4 +1,14 @@
 //! Authentication helper utilities
+//!
+//! Requests are authenticated with an access token, which is retrieved
+//! from a [`TokenProvider`] before each request. This can be either a
+//! fixed API access token, or an OAuth 2.0 access token which is refreshed
+//! as needed - see the [`oauth`] module for more info.
+//!
+pub mod oauth;
+mod provider;
+
+pub use provider::*;
 
 /// Returns the value to set in the `AUTHORIZATION` header for a request.
 pub fn auth_token(token: &str) -> String {
@@ -0,0 +1,470 @@
+//! Support for the Smartsheet [OAuth 2.0] *authorization code* flow.
+//!
+//! The flow is as follows:
+//!
+//! 1. Direct the user to the URL returned by
+//!    [`OAuthClient::authorize_url`], with the requested scopes and a
+//!    `state` value to protect against CSRF.
+//! 2. Once the user approves, Smartsheet redirects back to the app with a
+//!    `code` (and the same `state`) in the query string.
+//! 3. Exchange the `code` for an access token and a refresh token, via
+//!    [`OAuthClient::exchange_code`].
+//! 4. Create a client with [`SmartsheetApi::from_provider`], passing in an
+//!    [`OAuthTokenProvider`] - which refreshes the access token when it
+//!    expires.
+//!
+//! [OAuth 2.0]: https://smartsheet.redoc.ly/#section/OAuth-Walkthrough
+//! [`SmartsheetApi::from_provider`]: crate::SmartsheetApi::from_provider
+//!
+use crate::auth::TokenProvider;
+use crate::constants::{OAUTH_AUTHORIZE_URL, OAUTH_TOKEN_URL};
+use crate::https::{build_https_client, HttpVersion, HttpsClient, HttpsConfig};
+use crate::log::debug;
+use crate::proxy::Proxy;
+use crate::region::Region;
+use crate::status::raise_for_status;
+use crate::tls::TlsConfig;
+use crate::types::Result;
+use crate::utils::{encode_uri_component, into_struct_from_slice};
+
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use hyper::header::CONTENT_TYPE;
+use hyper::{Body, Request};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::sync::Mutex;
+
+/// Number of seconds before the actual expiry, at which an access token is
+/// considered to be expired.
+const EXPIRY_MARGIN_SECS: u64 = 60;
+
+/// Access scopes which can be requested for an OAuth access token.
+///
+/// # Docs
+/// <https://smartsheet.redoc.ly/#section/OAuth-Walkthrough/Access-Scopes>
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OAuthScope {
+    AdminSheets,
+    AdminSights,
+    AdminUsers,
+    AdminWebhooks,
+    AdminWorkspaces,
+    CreateSheets,
+    CreateSights,
+    DeleteSheets,
+    DeleteSights,
+    ReadContacts,
+    ReadEvents,
+    ReadSheets,
+    ReadSights,
+    ReadUsers,
+    ShareSheets,
+    ShareSights,
+    WriteSheets,
+}
+
+impl OAuthScope {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::AdminSheets => "ADMIN_SHEETS",
+            Self::AdminSights => "ADMIN_SIGHTS",
+            Self::AdminUsers => "ADMIN_USERS",
+            Self::AdminWebhooks => "ADMIN_WEBHOOKS",
+            Self::AdminWorkspaces => "ADMIN_WORKSPACES",
+            Self::CreateSheets => "CREATE_SHEETS",
+            Self::CreateSights => "CREATE_SIGHTS",
+            Self::DeleteSheets => "DELETE_SHEETS",
+            Self::DeleteSights => "DELETE_SIGHTS",
+            Self::ReadContacts => "READ_CONTACTS",
+            Self::ReadEvents => "READ_EVENTS",
+            Self::ReadSheets => "READ_SHEETS",
+            Self::ReadSights => "READ_SIGHTS",
+            Self::ReadUsers => "READ_USERS",
+            Self::ShareSheets => "SHARE_SHEETS",
+            Self::ShareSights => "SHARE_SIGHTS",
+            Self::WriteSheets => "WRITE_SHEETS",
+        }
+    }
+}
+
+impl fmt::Display for OAuthScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An OAuth 2.0 access token, along with the refresh token used to obtain
+/// a new access token once it expires.
+///
+/// This can be serialized, so that it can be persisted between runs.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OAuthToken {
+    /// The access token, used to authenticate requests.
+    pub access_token: String,
+    /// The type of token; this is always `bearer`.
+    #[serde(default)]
+    pub token_type: String,
+    /// The refresh token, used to obtain a new access token.
+    pub refresh_token: Option<String>,
+    /// Number of seconds until the access token expires, from the time it
+    /// was issued.
+    pub expires_in: Option<u64>,
+    /// Time (in seconds since the Unix epoch) when the access token
+    /// expires. This is not returned by the API, but is set when the
+    /// token is received.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+}
+
+impl OAuthToken {
+    /// Create a new `OAuthToken` from an *access token* and a *refresh
+    /// token*, for example when they are loaded from storage.
+    pub fn new<S: Into<String>>(access_token: S, refresh_token: S) -> Self {
+        Self {
+            access_token: access_token.into(),
+            token_type: "bearer".to_owned(),
+            refresh_token: Some(refresh_token.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Determine if the access token has expired - or is about to expire.
+    ///
+    /// Returns `false` if the expiry time is not known.
+    pub fn is_expired(&self) -> bool {
+        match self.expires_at {
+            Some(expires_at) => now_secs() + EXPIRY_MARGIN_SECS >= expires_at,
+            None => false,
+        }
+    }
+}
+
+/// Client for the Smartsheet OAuth 2.0 *authorization code* flow, which
+/// is used to obtain and refresh access tokens for a third-party app.
+#[derive(Clone)]
+pub struct OAuthClient {
+    client_id: String,
+    client_secret: String,
+    redirect_uri: Option<String>,
+    authorize_url: String,
+    token_url: String,
+    https: HttpsConfig,
+    client: HttpsClient,
+}
+
+impl OAuthClient {
+    /// Create a new `OAuthClient` from the *client id* and *app secret*
+    /// of a third-party app.
+    pub fn new<S: Into<String>>(client_id: S, client_secret: S) -> Self {
+        let https = HttpsConfig::from_env();
+        let client = build_https_client(&https);
+
+        Self {
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            redirect_uri: None,
+            authorize_url: OAUTH_AUTHORIZE_URL.to_owned(),
+            token_url: OAUTH_TOKEN_URL.to_owned(),
+            https,
+            client,
+        }
+    }
+
+    /// Set the *redirect URI*, which must match the one registered for the
+    /// app.
+    pub fn with_redirect_uri<S: Into<String>>(mut self, redirect_uri: S) -> Self {
+        self.redirect_uri = Some(redirect_uri.into());
+        self
+    }
+
+    /// Set the *authorize URL* and *token URL* for a `Region`, such as
+    /// Smartsheet EU or Smartsheetgov.
+    pub fn with_region(self, region: Region) -> Self {
+        self.with_authorize_url(region.oauth_authorize_url())
+            .with_token_url(region.oauth_token_url())
+    }
+
+    /// Set a custom *authorize URL*, for example for a different region.
+    pub fn with_authorize_url<S: Into<String>>(mut self, authorize_url: S) -> Self {
+        self.authorize_url = authorize_url.into();
+        self
+    }
+
+    /// Set a custom *token URL*, for example for a different region.
+    ///
+    /// The token URL must use `https`, as the app secret and the tokens are
+    /// sent to it; requests to an `http` URL - even a local one - fail.
+    pub fn with_token_url<S: Into<String>>(mut self, token_url: S) -> Self {
+        self.token_url = token_url.into();
+        self
+    }
+
+    /// Set the `Proxy` to connect to the *token URL* via, or connect
+    /// directly if `None`.
+    ///
+    /// By default, the proxy is set from the `HTTPS_PROXY` and `NO_PROXY`
+    /// environment variables.
+    pub fn with_proxy(mut self, proxy: impl Into<Option<Proxy>>) -> Self {
+        self.https.proxy = proxy.into();
+        self.client = build_https_client(&self.https);
+        self
+    }
+
+    /// Set a custom `TlsConfig` for the connections to Smartsheet - for
+    /// example, to trust a private CA.
+    ///
+    /// # Errors
+    /// Returns an error if the TLS configuration is invalid.
+    pub fn with_tls_config(mut self, tls: TlsConfig) -> Result<Self> {
+        self.https.tls = Some(tls.build()?);
+        self.client = build_https_client(&self.https);
+        Ok(self)
+    }
+
+    /// Set the `HttpVersion` to use for the requests to Smartsheet.
+    pub fn with_http_version(mut self, version: HttpVersion) -> Self {
+        self.https.version = version;
+        self.client = build_https_client(&self.https);
+        self
+    }
+
+    /// Returns the URL to direct the user to, in order to authorize the app
+    /// for the requested `scopes`.
+    ///
+    /// The `state` is returned as-is in the redirect, and should be
+    /// verified by the app.
+    pub fn authorize_url(&self, scopes: &[OAuthScope], state: &str) -> String {
+        let scope = scopes
+            .iter()
+            .map(OAuthScope::as_str)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mut url = format!(
+            "{}?response_type=code&client_id={}&scope={}&state={}",
+            self.authorize_url,
+            encode_uri_component(&self.client_id),
+            encode_uri_component(&scope),
+            encode_uri_component(state)
+        );
+
+        if let Some(redirect_uri) = &self.redirect_uri {
+            url.push_str("&redirect_uri=");
+            url.push_str(&encode_uri_component(redirect_uri));
+        }
+
+        url
+    }
+
+    /// Exchange an authorization `code` for an access token and a refresh
+    /// token.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/tokens-getOrRefresh
+    ///
+    pub async fn exchange_code(&self, code: &str) -> Result<OAuthToken> {
+        self.request_token("authorization_code", "code", code).await
+    }
+
+    /// Obtain a new access token, using a `refresh_token`.
+    ///
+    /// # Docs
+    /// - https://smartsheet.redoc.ly/#operation/tokens-getOrRefresh
+    ///
+    pub async fn refresh_token(&self, refresh_token: &str) -> Result<OAuthToken> {
+        self.request_token("refresh_token", "refresh_token", refresh_token)
+            .await
+    }
+
+    /// Create an `OAuthTokenProvider` from an access token, which can then
+    /// be passed in to `SmartsheetApi::from_provider`.
+    pub fn into_provider(self, token: OAuthToken) -> OAuthTokenProvider {
+        OAuthTokenProvider {
+            oauth: self,
+            token: Mutex::new(token),
+        }
+    }
+
+    /// Returns the SHA-256 hash - as a hex string - of the app secret and
+    /// the `value`, separated by a pipe.
+    fn hash(&self, value: &str) -> String {
+        let digest = Sha256::digest(format!("{}|{}", self.client_secret, value));
+
+        digest.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    /// Internal method to request an access token from the token URL.
+    async fn request_token(&self, grant_type: &str, key: &str, value: &str) -> Result<OAuthToken> {
+        let url = self.token_url.to_owned();
+
+        debug!("URL: {}", url);
+
+        let mut form = vec![
+            ("grant_type", grant_type),
+            ("client_id", &self.client_id),
+            (key, value),
+        ];
+        let hash = self.hash(value);
+        form.push(("hash", &hash));
+        if let Some(redirect_uri) = &self.redirect_uri {
+            form.push(("redirect_uri", redirect_uri));
+        }
+
+        let data = form
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, encode_uri_component(v)))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        let req = Request::post(&url)
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(Body::from(data))?;
+
+        let mut res = self.client.request(req).await?;
+        raise_for_status(url, &mut res).await?;
+
+        let mut token: OAuthToken = into_struct_from_slice(res).await?;
+
+        token.expires_at = token.expires_in.map(|secs| now_secs() + secs);
+
+        Ok(token)
+    }
+}
+
+/// A `TokenProvider` for an OAuth 2.0 access token, which is refreshed
+/// when it expires - or when a request fails due to an expired token.
+pub struct OAuthTokenProvider {
+    oauth: OAuthClient,
+    token: Mutex<OAuthToken>,
+}
+
+impl OAuthTokenProvider {
+    /// Returns the current `OAuthToken`, for example so that it can be
+    /// persisted after it is refreshed.
+    pub async fn token(&self) -> OAuthToken {
+        self.token.lock().await.clone()
+    }
+
+    /// Internal method to refresh the token, while the lock is held.
+    async fn refresh_locked(&self, token: &mut OAuthToken) -> Result<bool> {
+        let refresh_token = match &token.refresh_token {
+            Some(refresh_token) => refresh_token,
+            None => return Ok(false),
+        };
+
+        *token = self.oauth.refresh_token(refresh_token).await?;
+
+        Ok(true)
+    }
+}
+
+#[async_trait]
+impl TokenProvider for OAuthTokenProvider {
+    async fn access_token(&self) -> Result<String> {
+        let mut token = self.token.lock().await;
+
+        if token.is_expired() {
+            debug!("Access token has expired, refreshing");
+            self.refresh_locked(&mut token).await?;
+        }
+
+        Ok(token.access_token.to_owned())
+    }
+
+    async fn refresh(&self, expired_token: &str) -> Result<bool> {
+        let mut token = self.token.lock().await;
+
+        // Another request has already refreshed the token.
+        if token.access_token != expired_token {
+            return Ok(true);
+        }
+
+        self.refresh_locked(&mut token).await
+    }
+}
+
+/// Returns the current time, in seconds since the Unix epoch.
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::MockServer;
+
+    const TOKEN_JSON: &str = r#"{
+        "access_token": "new-access-token",
+        "token_type": "bearer",
+        "refresh_token": "new-refresh-token",
+        "expires_in": 604799
+    }"#;
+
+    #[test]
+    fn test_authorize_url() {
+        let oauth = OAuthClient::new("my-client", "secret").with_redirect_uri("https://a.b/cb");
+
+        assert_eq!(
+            oauth.authorize_url(&[OAuthScope::ReadSheets, OAuthScope::WriteSheets], "xyz"),
+            "https://app.smartsheet.com/b/authorize?response_type=code&client_id=my-client\
+            &scope=READ_SHEETS%20WRITE_SHEETS&state=xyz&redirect_uri=https%3A%2F%2Fa.b%2Fcb"
+        );
+    }
+
+    #[test]
+    fn test_hash() {
+        let oauth = OAuthClient::new("my-client", "secret");
+
+        // echo -n 'secret|code' | sha256sum
+        assert_eq!(
+            oauth.hash("code"),
+            "875ccbea123ce4cf3682ad9f52be303c7a92c2eddf00e24bbeb583c3bf28765a"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exchange_code() {
+        let server = MockServer::start(vec![(200, TOKEN_JSON)]);
+
+        let oauth = OAuthClient::new("my-client", "secret").with_token_url(server.url("/token"));
+        let token = oauth.exchange_code("the-code").await.unwrap();
+
+        assert_eq!(token.access_token, "new-access-token");
+        assert_eq!(token.refresh_token.as_deref(), Some("new-refresh-token"));
+        assert!(!token.is_expired());
+
+        let request = &server.requests()[0];
+        assert!(request.starts_with("POST /token "));
+        assert!(request.ends_with(&format!(
+            "grant_type=authorization_code&client_id=my-client&code=the-code&hash={}",
+            oauth.hash("the-code")
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_provider_refreshes_expired_token() {
+        let server = MockServer::start(vec![(200, TOKEN_JSON)]);
+
+        let mut token = OAuthToken::new("old-access-token", "old-refresh-token");
+        token.expires_at = Some(now_secs());
+
+        let provider = OAuthClient::new("my-client", "secret")
+            .with_token_url(server.url("/token"))
+            .into_provider(token);
+
+        assert_eq!(provider.access_token().await.unwrap(), "new-access-token");
+        assert!(server.requests()[0].contains("grant_type=refresh_token"));
+        assert!(server.requests()[0].contains("refresh_token=old-refresh-token"));
+
+        // The token was already refreshed, so this is a no-op.
+        assert!(provider.refresh("old-access-token").await.unwrap());
+        assert_eq!(server.requests().len(), 1);
+    }
+}
@@ -0,0 +1,313 @@
+use crate::constants::ENV_VAR_NAME;
+use crate::types::Result;
+
+use std::future::Future;
+use std::io::{Error, ErrorKind};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+use async_trait::async_trait;
+
+/// A **Token Provider** supplies the access token used to authenticate
+/// requests to the Smartsheet API.
+///
+/// The client asks the provider for a token before each request, so an
+/// implementation is free to rotate or refresh the token as needed.
+///
+/// The following implementations are provided:
+/// - [`StaticToken`] - a fixed API access token
+/// - [`EnvToken`] - an environment variable, read at call time
+/// - [`FileToken`] - a file, which is re-read periodically
+/// - [`FnToken`] - a caller-supplied (async) closure
+/// - [`OAuthTokenProvider`] - an OAuth 2.0 access token, which is
+///   refreshed when it expires
+///
+/// [`OAuthTokenProvider`]: crate::auth::oauth::OAuthTokenProvider
+#[async_trait]
+pub trait TokenProvider: Send + Sync {
+    /// Returns the access token to use for the next request.
+    async fn access_token(&self) -> Result<String>;
+
+    /// Called when a request fails because the `expired_token` is no longer
+    /// valid - for example, when an OAuth access token has expired.
+    ///
+    /// Returns `true` if a new token is available, in which case the
+    /// request is retried once. The default implementation returns `false`,
+    /// as a fixed token cannot be refreshed.
+    async fn refresh(&self, expired_token: &str) -> Result<bool> {
+        let _ = expired_token;
+        Ok(false)
+    }
+}
+
+#[async_trait]
+impl<T: TokenProvider + ?Sized> TokenProvider for Arc<T> {
+    async fn access_token(&self) -> Result<String> {
+        (**self).access_token().await
+    }
+
+    async fn refresh(&self, expired_token: &str) -> Result<bool> {
+        (**self).refresh(expired_token).await
+    }
+}
+
+/// A fixed API access token, which never changes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaticToken(String);
+
+impl StaticToken {
+    /// Create a new `StaticToken` from an API access token.
+    pub fn new<S: Into<String>>(token: S) -> Self {
+        Self(token.into())
+    }
+}
+
+#[async_trait]
+impl TokenProvider for StaticToken {
+    async fn access_token(&self) -> Result<String> {
+        Ok(self.0.to_owned())
+    }
+}
+
+/// An API access token which is read from an environment variable before
+/// each request, so that it can be rotated without rebuilding the client.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnvToken {
+    name: String,
+}
+
+impl EnvToken {
+    /// Create a new `EnvToken` for the environment variable `name`.
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self { name: name.into() }
+    }
+
+    /// Internal method to read the token, with `lookup` returning the value
+    /// of an environment variable.
+    fn read(&self, lookup: impl Fn(&str) -> Option<String>) -> Result<String> {
+        match lookup(&self.name) {
+            Some(token) => Ok(token),
+            None => Err(Box::from(Error::new(
+                ErrorKind::NotFound,
+                format!("Environment variable `{}` must be set.", self.name),
+            ))),
+        }
+    }
+}
+
+impl Default for EnvToken {
+    /// Create a new `EnvToken` for the `SMARTSHEET_ACCESS_TOKEN`
+    /// environment variable.
+    fn default() -> Self {
+        Self::new(ENV_VAR_NAME)
+    }
+}
+
+#[async_trait]
+impl TokenProvider for EnvToken {
+    async fn access_token(&self) -> Result<String> {
+        self.read(|name| std::env::var(name).ok())
+    }
+
+    async fn refresh(&self, expired_token: &str) -> Result<bool> {
+        Ok(self.access_token().await? != expired_token)
+    }
+}
+
+/// An API access token which is read from a file, for example one which is
+/// mounted by a secrets manager.
+///
+/// The file is watched for changes: its modification time is checked on
+/// each request, and the token is re-read whenever the file is modified.
+/// As a fallback for file systems which don't report modification times,
+/// the file is also re-read once the cached token is older than the
+/// time-to-live (one minute by default), or when a request fails because
+/// the token has expired. Any surrounding whitespace is trimmed from the
+/// token.
+#[derive(Debug)]
+pub struct FileToken {
+    path: PathBuf,
+    ttl: Duration,
+    cached: Mutex<Option<CachedToken>>,
+}
+
+/// Internal struct for the token last read by a `FileToken`.
+#[derive(Debug)]
+struct CachedToken {
+    modified: Option<SystemTime>,
+    read_at: Instant,
+    token: String,
+}
+
+impl FileToken {
+    /// The default time-to-live of a token read from the file.
+    pub const DEFAULT_TTL: Duration = Duration::from_secs(60);
+
+    /// Create a new `FileToken` for the file at `path`.
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self {
+            path: path.into(),
+            ttl: Self::DEFAULT_TTL,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Set the time-to-live of a token read from the file, after which the
+    /// file is read again.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Internal method to read the token, if the file was modified or the
+    /// cached token has expired - or if `force` is true.
+    async fn read(&self, force: bool) -> Result<String> {
+        let modified = tokio::fs::metadata(&self.path).await?.modified().ok();
+
+        if !force {
+            if let Some(cached) = &*self.cached.lock().unwrap() {
+                if cached.modified == modified && cached.read_at.elapsed() < self.ttl {
+                    return Ok(cached.token.to_owned());
+                }
+            }
+        }
+
+        let token = tokio::fs::read_to_string(&self.path)
+            .await?
+            .trim()
+            .to_owned();
+        *self.cached.lock().unwrap() = Some(CachedToken {
+            modified,
+            read_at: Instant::now(),
+            token: token.to_owned(),
+        });
+
+        Ok(token)
+    }
+}
+
+#[async_trait]
+impl TokenProvider for FileToken {
+    async fn access_token(&self) -> Result<String> {
+        self.read(false).await
+    }
+
+    async fn refresh(&self, expired_token: &str) -> Result<bool> {
+        Ok(self.read(true).await? != expired_token)
+    }
+}
+
+/// An API access token which is supplied by a caller-provided async
+/// closure, for example one which fetches it from a secrets manager.
+///
+/// # Example
+///
+/// ```
+/// use smartsheet_rs::auth::FnToken;
+///
+/// let provider = FnToken::new(|| async { Ok("MY-TOKEN".to_owned()) });
+/// ```
+pub struct FnToken<F> {
+    f: F,
+}
+
+impl<F, Fut> FnToken<F>
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = Result<String>> + Send,
+{
+    /// Create a new `FnToken` from a closure, which is called before each
+    /// request.
+    pub fn new(f: F) -> Self {
+        Self { f }
+    }
+}
+
+#[async_trait]
+impl<F, Fut> TokenProvider for FnToken<F>
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = Result<String>> + Send,
+{
+    async fn access_token(&self) -> Result<String> {
+        (self.f)().await
+    }
+
+    async fn refresh(&self, expired_token: &str) -> Result<bool> {
+        Ok((self.f)().await? != expired_token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_static_token() {
+        let provider = StaticToken::new("abc");
+
+        assert_eq!(provider.access_token().await.unwrap(), "abc");
+        assert!(!provider.refresh("abc").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_env_token() {
+        // The environment is not modified here, as other tests read it
+        // concurrently; the variables are looked up in a map instead.
+        let name = "SMARTSHEET_RS_TEST_ENV_TOKEN";
+        let provider = EnvToken::new(name);
+        let env = Mutex::new(HashMap::new());
+        let lookup = |name: &str| env.lock().unwrap().get(name).cloned();
+
+        assert!(provider.read(lookup).is_err());
+        assert!(provider.access_token().await.is_err());
+
+        env.lock().unwrap().insert(name, "first".to_owned());
+        assert_eq!(provider.read(lookup).unwrap(), "first");
+
+        env.lock().unwrap().insert(name, "second".to_owned());
+        assert_eq!(provider.read(lookup).unwrap(), "second");
+    }
+
+    #[tokio::test]
+    async fn test_file_token() {
+        let path = std::env::temp_dir().join(format!(
+            "smartsheet-rs-test-file-token-{}",
+            std::process::id()
+        ));
+        std::fs::write(&path, "first\n").unwrap();
+
+        let provider = FileToken::new(&path);
+        assert_eq!(provider.access_token().await.unwrap(), "first");
+        assert!(!provider.refresh("first").await.unwrap());
+
+        // The file is read again as soon as it's modified. Wait a little
+        // first, so the change isn't within the resolution of the mtime.
+        std::thread::sleep(Duration::from_millis(50));
+        std::fs::write(&path, "second\n").unwrap();
+        assert_eq!(provider.access_token().await.unwrap(), "second");
+        assert!(provider.refresh("first").await.unwrap());
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(provider.access_token().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fn_token() {
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let counter = calls.clone();
+        let provider = FnToken::new(move || {
+            let n = counter.fetch_add(1, Ordering::SeqCst);
+            async move { Ok(format!("token-{}", n)) }
+        });
+
+        assert_eq!(provider.access_token().await.unwrap(), "token-0");
+        assert_eq!(provider.access_token().await.unwrap(), "token-1");
+        assert!(provider.refresh("token-1").await.unwrap());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+}
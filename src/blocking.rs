@@ -0,0 +1,1112 @@
+//! A blocking (synchronous) client for the *Smartsheet API v2*, which is
+//! available with the `blocking` feature.
+//!
+//! Each method mirrors the one of the same name on the async
+//! [`SmartsheetApi`](crate::SmartsheetApi), and shares the same models
+//! and helpers; the request is run to completion on an internal
+//! (current-thread) Tokio runtime, which is shared by all clones of the
+//! client.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use smartsheet_rs::blocking::SmartsheetApi;
+//!
+//! fn main() -> std::result::Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//!     let smart = SmartsheetApi::from_env()?;
+//!
+//!     let sheets = smart.list_sheets()?;
+//!     println!("Sheet Count: {}", sheets.total_count);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Note that the blocking client must **not** be used from within an async
+//! context, such as in a function marked `#[tokio::main]`; use the async
+//! client there instead.
+//!
+use crate::auth::TokenProvider;
+use crate::https::HttpVersion;
+use crate::middleware::Middleware;
+use crate::models::*;
+use crate::proxy::Proxy;
+use crate::rate_limit::RateLimiter;
+use crate::region::Region;
+use crate::response;
+use crate::tls::TlsConfig;
+use crate::types::Result;
+
+use std::sync::Arc;
+
+use hyper::body::Bytes;
+use tokio::runtime::{Builder, Runtime};
+
+/// Blocking client implementation for making requests to the *Smartsheet
+/// API v2*
+///
+/// The client is cheap to clone, and clones share the same connection
+/// pool and runtime.
+///
+/// # Links
+/// - [`smartsheet-rs`](https://docs.rs/smartsheet-rs)
+/// - [Official Documentation](https://smartsheet-platform.github.io/api-docs/)
+///
+#[derive(Clone)]
+pub struct SmartsheetApi {
+    api: crate::SmartsheetApi,
+    rt: Arc<Runtime>,
+}
+
+impl From<crate::SmartsheetApi> for SmartsheetApi {
+    /// Wraps an async `SmartsheetApi` client, keeping its configuration
+    /// - such as the token provider, headers, and rate limiter.
+    ///
+    /// The blocking client has its own connection pool, as connections
+    /// are driven by the runtime they were opened on.
+    fn from(api: crate::SmartsheetApi) -> Self {
+        let rt = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to build the Tokio runtime for the blocking client");
+
+        Self {
+            api: api.with_new_pool(),
+            rt: Arc::new(rt),
+        }
+    }
+}
+
+impl SmartsheetApi {
+    /// Initialize a new `SmartsheetApi` object from an API access token.
+    pub fn from_token(token: &str) -> Self {
+        crate::SmartsheetApi::from_token(token).into()
+    }
+
+    /// Initialize a new `SmartsheetApi` object from an API access token,
+    /// assuming this is currently set in the environment.
+    ///
+    /// The `Region` can also be set in the environment, via the
+    /// `SMARTSHEET_REGION` variable; if not set, this defaults to `US`.
+    pub fn from_env() -> Result<Self> {
+        Ok(crate::SmartsheetApi::from_env()?.into())
+    }
+
+    /// Initialize a new `SmartsheetApi` object from a `Region`, and an
+    /// access token.
+    pub fn from_region_and_token(region: Region, token: &str) -> Self {
+        crate::SmartsheetApi::from_region_and_token(region, token).into()
+    }
+
+    /// Initialize a new `SmartsheetApi` object from a `Region`, and a
+    /// `TokenProvider`.
+    pub fn from_region_and_provider(
+        region: Region,
+        provider: impl TokenProvider + 'static,
+    ) -> Self {
+        crate::SmartsheetApi::from_region_and_provider(region, provider).into()
+    }
+
+    /// Initialize a new `SmartsheetApi` object from a (custom) base API
+    /// endpoint, and an access token.
+    pub fn from_endpoint_and_token(endpoint: &str, token: &str) -> Self {
+        crate::SmartsheetApi::from_endpoint_and_token(endpoint, token).into()
+    }
+
+    /// Initialize a new `SmartsheetApi` object from a `TokenProvider`,
+    /// which supplies the access token before each request.
+    pub fn from_provider(provider: impl TokenProvider + 'static) -> Self {
+        crate::SmartsheetApi::from_provider(provider).into()
+    }
+
+    /// Initialize a new `SmartsheetApi` object from a (custom) base API
+    /// endpoint, and a `TokenProvider`.
+    pub fn from_endpoint_and_provider(
+        endpoint: &str,
+        provider: impl TokenProvider + 'static,
+    ) -> Self {
+        crate::SmartsheetApi::from_endpoint_and_provider(endpoint, provider).into()
+    }
+
+    /// Returns a copy of the client, with the async client replaced by the
+    /// result of `f`; for internal use
+    fn map(&self, f: impl FnOnce(&crate::SmartsheetApi) -> crate::SmartsheetApi) -> Self {
+        Self {
+            api: f(&self.api),
+            rt: self.rt.clone(),
+        }
+    }
+
+    /// Returns the `Region` of the Smartsheet API which the client makes
+    /// requests to.
+    pub fn region(&self) -> Region {
+        self.api.region()
+    }
+
+    /// Returns a copy of the client which makes requests on behalf of the
+    /// user with the specified `email`, via the `Assume-User` header.
+    ///
+    /// See [`SmartsheetApi::as_user`](crate::SmartsheetApi::as_user).
+    pub fn as_user(&self, email: &str) -> Self {
+        self.map(|api| api.as_user(email))
+    }
+
+    /// Returns a copy of the client which waits for requests to fit within
+    /// the rate limit of a `RateLimiter`.
+    ///
+    /// See [`SmartsheetApi::with_rate_limiter`](crate::SmartsheetApi::with_rate_limiter).
+    pub fn with_rate_limiter(&self, limiter: RateLimiter) -> Self {
+        self.map(|api| api.with_rate_limiter(limiter))
+    }
+
+    /// Returns a copy of the client which connects to the Smartsheet API via
+    /// a `Proxy`, or directly if `None`.
+    ///
+    /// See [`SmartsheetApi::with_proxy`](crate::SmartsheetApi::with_proxy).
+    pub fn with_proxy(&self, proxy: impl Into<Option<Proxy>>) -> Self {
+        self.map(|api| api.with_proxy(proxy))
+    }
+
+    /// Returns a copy of the client which uses a custom `TlsConfig` for the
+    /// connections to the Smartsheet API.
+    ///
+    /// See [`SmartsheetApi::with_tls_config`](crate::SmartsheetApi::with_tls_config).
+    pub fn with_tls_config(&self, tls: TlsConfig) -> Result<Self> {
+        Ok(Self {
+            api: self.api.with_tls_config(tls)?,
+            rt: self.rt.clone(),
+        })
+    }
+
+    /// Returns a copy of the client which uses an `HttpVersion` for the
+    /// requests to the Smartsheet API.
+    ///
+    /// See [`SmartsheetApi::with_http_version`](crate::SmartsheetApi::with_http_version).
+    pub fn with_http_version(&self, version: HttpVersion) -> Self {
+        self.map(|api| api.with_http_version(version))
+    }
+
+    /// Returns a copy of the client, with a `Middleware` added after any
+    /// existing ones.
+    ///
+    /// See [`SmartsheetApi::with_middleware`](crate::SmartsheetApi::with_middleware).
+    pub fn with_middleware(&self, middleware: impl Middleware + 'static) -> Self {
+        self.map(|api| api.with_middleware(middleware))
+    }
+
+    /// Returns a copy of the client which identifies its changes as being
+    /// made by the integration `agent`, via the `Smartsheet-Change-Agent`
+    /// header.
+    ///
+    /// # Errors
+    /// Returns an error if `agent` is not a valid header value.
+    pub fn with_change_agent(&self, agent: &str) -> Result<Self> {
+        Ok(Self {
+            api: self.api.with_change_agent(agent)?,
+            rt: self.rt.clone(),
+        })
+    }
+
+    /// Calls one (or more) methods on a copy of the client, and returns the
+    /// result along with the metadata of the last response.
+    ///
+    /// ```no_run
+    /// # fn run(smart: smartsheet_rs::blocking::SmartsheetApi) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// let res = smart.with_meta(|api| api.list_sheets())?;
+    ///
+    /// println!("{} took {:?}", res.meta.url, res.meta.elapsed);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_meta<T, F>(&self, f: F) -> Result<response::Response<T>>
+    where
+        F: FnOnce(Self) -> Result<T>,
+    {
+        let (api, slot) = self.api.recording_meta();
+
+        let data = f(Self {
+            api,
+            rt: self.rt.clone(),
+        })?;
+
+        crate::SmartsheetApi::take_meta(&slot, data)
+    }
+
+    /// **List Sheets** - Blocking version of [`SmartsheetApi::list_sheets`](crate::SmartsheetApi::list_sheets).
+    pub fn list_sheets(&self) -> Result<IndexResult<Sheet>> {
+        self.rt.block_on(self.api.list_sheets())
+    }
+
+    /// **List Sheets** - Blocking version of [`SmartsheetApi::list_sheets_with_params`](crate::SmartsheetApi::list_sheets_with_params).
+    pub fn list_sheets_with_params<'a>(
+        &self,
+        include: impl Into<Option<Vec<ListSheetIncludeFlags>>>,
+        include_all: impl Into<Option<bool>>,
+        modified_since: impl Into<Option<&'a str>>,
+    ) -> Result<IndexResult<Sheet>> {
+        self.rt.block_on(
+            self.api
+                .list_sheets_with_params(include, include_all, modified_since),
+        )
+    }
+
+    /// **Get Sheet** - Blocking version of [`SmartsheetApi::get_sheet`](crate::SmartsheetApi::get_sheet).
+    pub fn get_sheet(&self, sheet_id: u64) -> Result<Sheet> {
+        self.rt.block_on(self.api.get_sheet(sheet_id))
+    }
+
+    /// **Get Sheet** - Blocking version of [`SmartsheetApi::get_sheet_with_multi_contact_info`](crate::SmartsheetApi::get_sheet_with_multi_contact_info).
+    pub fn get_sheet_with_multi_contact_info(&self, sheet_id: u64) -> Result<Sheet> {
+        self.rt
+            .block_on(self.api.get_sheet_with_multi_contact_info(sheet_id))
+    }
+
+    /// **Get Sheet** - Blocking version of [`SmartsheetApi::get_sheet_with_params`](crate::SmartsheetApi::get_sheet_with_params).
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_sheet_with_params<'a>(
+        &self,
+        sheet_id: u64,
+        include: impl Into<Option<Vec<SheetIncludeFlags>>>,
+        exclude: impl Into<Option<Vec<SheetExcludeFlags>>>,
+        row_ids: impl Into<Option<Vec<u64>>>,
+        row_numbers: impl Into<Option<Vec<u64>>>,
+        column_ids: impl Into<Option<Vec<u64>>>,
+        rows_modified_since: impl Into<Option<&'a str>>,
+        level: impl Into<Option<Level>>,
+    ) -> Result<Sheet> {
+        self.rt.block_on(self.api.get_sheet_with_params(
+            sheet_id,
+            include,
+            exclude,
+            row_ids,
+            row_numbers,
+            column_ids,
+            rows_modified_since,
+            level,
+        ))
+    }
+
+    /// **Get Sheet as Excel / PDF / CSV** - Blocking version of [`SmartsheetApi::export_sheet`](crate::SmartsheetApi::export_sheet).
+    pub fn export_sheet(&self, sheet_id: u64, format: ExportFormat) -> Result<Bytes> {
+        self.rt.block_on(async {
+            let body = self.api.export_sheet(sheet_id, format).await?;
+            Ok(hyper::body::to_bytes(body).await?)
+        })
+    }
+
+    /// **Get Report as Excel / CSV** - Blocking version of [`SmartsheetApi::export_report`](crate::SmartsheetApi::export_report).
+    pub fn export_report(&self, report_id: u64, format: ExportFormat) -> Result<Bytes> {
+        self.rt.block_on(async {
+            let body = self.api.export_report(report_id, format).await?;
+            Ok(hyper::body::to_bytes(body).await?)
+        })
+    }
+
+    /// **Get Row** - Blocking version of [`SmartsheetApi::get_row`](crate::SmartsheetApi::get_row).
+    pub fn get_row(&self, sheet_id: u64, row_id: u64) -> Result<Row> {
+        self.rt.block_on(self.api.get_row(sheet_id, row_id))
+    }
+
+    /// **Get Row** - Blocking version of [`SmartsheetApi::get_row_with_column_data`](crate::SmartsheetApi::get_row_with_column_data).
+    pub fn get_row_with_column_data(&self, sheet_id: u64, row_id: u64) -> Result<Row> {
+        self.rt
+            .block_on(self.api.get_row_with_column_data(sheet_id, row_id))
+    }
+
+    /// **Get Row** - Blocking version of [`SmartsheetApi::get_row_with_multi_contact_info`](crate::SmartsheetApi::get_row_with_multi_contact_info).
+    pub fn get_row_with_multi_contact_info(&self, sheet_id: u64, row_id: u64) -> Result<Row> {
+        self.rt
+            .block_on(self.api.get_row_with_multi_contact_info(sheet_id, row_id))
+    }
+
+    /// **Get Row** - Blocking version of [`SmartsheetApi::get_row_with_params`](crate::SmartsheetApi::get_row_with_params).
+    pub fn get_row_with_params(
+        &self,
+        sheet_id: u64,
+        row_id: u64,
+        include: impl Into<Option<Vec<RowIncludeFlags>>>,
+        exclude: impl Into<Option<Vec<RowExcludeFlags>>>,
+        level: impl Into<Option<Level>>,
+    ) -> Result<Row> {
+        self.rt.block_on(
+            self.api
+                .get_row_with_params(sheet_id, row_id, include, exclude, level),
+        )
+    }
+
+    /// **Add Rows** - Blocking version of [`SmartsheetApi::add_rows`](crate::SmartsheetApi::add_rows).
+    pub fn add_rows(&self, sheet_id: u64, rows: impl Into<Vec<Row>>) -> Result<RowResult> {
+        self.rt.block_on(self.api.add_rows(sheet_id, rows))
+    }
+
+    /// **Add Rows** - Blocking version of [`SmartsheetApi::add_rows_with_params`](crate::SmartsheetApi::add_rows_with_params).
+    pub fn add_rows_with_params(
+        &self,
+        sheet_id: u64,
+        rows: impl Into<Vec<Row>>,
+        allow_partial_success: impl Into<Option<bool>>,
+        override_validation: impl Into<Option<bool>>,
+    ) -> Result<RowResult> {
+        self.rt.block_on(self.api.add_rows_with_params(
+            sheet_id,
+            rows,
+            allow_partial_success,
+            override_validation,
+        ))
+    }
+
+    /// **Update Rows** - Blocking version of [`SmartsheetApi::update_rows`](crate::SmartsheetApi::update_rows).
+    pub fn update_rows(&self, sheet_id: u64, rows: impl Into<Vec<Row>>) -> Result<RowResult> {
+        self.rt.block_on(self.api.update_rows(sheet_id, rows))
+    }
+
+    /// **Update Rows** - Blocking version of [`SmartsheetApi::update_rows_with_params`](crate::SmartsheetApi::update_rows_with_params).
+    pub fn update_rows_with_params(
+        &self,
+        sheet_id: u64,
+        rows: impl Into<Vec<Row>>,
+        allow_partial_success: impl Into<Option<bool>>,
+        override_validation: impl Into<Option<bool>>,
+    ) -> Result<RowResult> {
+        self.rt.block_on(self.api.update_rows_with_params(
+            sheet_id,
+            rows,
+            allow_partial_success,
+            override_validation,
+        ))
+    }
+
+    /// **Delete Rows** - Blocking version of [`SmartsheetApi::delete_rows`](crate::SmartsheetApi::delete_rows).
+    pub fn delete_rows<const N: usize>(
+        &self,
+        sheet_id: u64,
+        row_ids: impl Into<[u64; N]>,
+    ) -> Result<RowResult<u64>> {
+        self.rt.block_on(self.api.delete_rows(sheet_id, row_ids))
+    }
+
+    /// **Delete Rows** - Blocking version of [`SmartsheetApi::delete_rows_with_params`](crate::SmartsheetApi::delete_rows_with_params).
+    pub fn delete_rows_with_params<const N: usize>(
+        &self,
+        sheet_id: u64,
+        row_ids: impl Into<[u64; N]>,
+        ignore_rows_not_found: impl Into<Option<bool>>,
+    ) -> Result<RowResult<u64>> {
+        self.rt.block_on(
+            self.api
+                .delete_rows_with_params(sheet_id, row_ids, ignore_rows_not_found),
+        )
+    }
+
+    /// **List Columns** - Blocking version of [`SmartsheetApi::list_columns`](crate::SmartsheetApi::list_columns).
+    pub fn list_columns(&self, sheet_id: u64) -> Result<IndexResult<Column>> {
+        self.rt.block_on(self.api.list_columns(sheet_id))
+    }
+
+    /// **List Columns** - Blocking version of [`SmartsheetApi::list_columns_with_params`](crate::SmartsheetApi::list_columns_with_params).
+    pub fn list_columns_with_params(
+        &self,
+        sheet_id: u64,
+        level: impl Into<Option<Level>>,
+        include: impl Into<Option<Vec<ColumnIncludeFlags>>>,
+        include_all: impl Into<Option<bool>>,
+    ) -> Result<IndexResult<Column>> {
+        self.rt.block_on(
+            self.api
+                .list_columns_with_params(sheet_id, level, include, include_all),
+        )
+    }
+
+    /// **Get Column** - Blocking version of [`SmartsheetApi::get_column`](crate::SmartsheetApi::get_column).
+    pub fn get_column(&self, sheet_id: u64, column_id: u64) -> Result<Column> {
+        self.rt.block_on(self.api.get_column(sheet_id, column_id))
+    }
+
+    /// **Get Column** - Blocking version of [`SmartsheetApi::get_column_with_params`](crate::SmartsheetApi::get_column_with_params).
+    pub fn get_column_with_params(
+        &self,
+        sheet_id: u64,
+        column_id: u64,
+        level: impl Into<Option<Level>>,
+        include: impl Into<Option<Vec<ColumnIncludeFlags>>>,
+    ) -> Result<Column> {
+        self.rt.block_on(
+            self.api
+                .get_column_with_params(sheet_id, column_id, level, include),
+        )
+    }
+
+    /// **List Attachments** - Blocking version of [`SmartsheetApi::list_attachments`](crate::SmartsheetApi::list_attachments).
+    pub fn list_attachments(&self, sheet_id: u64) -> Result<IndexResult<AttachmentMeta>> {
+        self.rt.block_on(self.api.list_attachments(sheet_id))
+    }
+
+    /// **Get Attachment** - Blocking version of [`SmartsheetApi::get_attachment`](crate::SmartsheetApi::get_attachment).
+    pub fn get_attachment(&self, sheet_id: u64, attachment_id: u64) -> Result<Attachment> {
+        self.rt
+            .block_on(self.api.get_attachment(sheet_id, attachment_id))
+    }
+
+    /// **Attach File to Sheet** - Blocking version of [`SmartsheetApi::attach_file_to_sheet`](crate::SmartsheetApi::attach_file_to_sheet).
+    pub fn attach_file_to_sheet(
+        &self,
+        sheet_id: u64,
+        file_name: &str,
+        content_type: &str,
+        data: impl Into<Vec<u8>>,
+    ) -> Result<ItemResult<AttachmentMeta>> {
+        self.rt.block_on(
+            self.api
+                .attach_file_to_sheet(sheet_id, file_name, content_type, data),
+        )
+    }
+
+    /// **Attach File to Row** - Blocking version of [`SmartsheetApi::attach_file_to_row`](crate::SmartsheetApi::attach_file_to_row).
+    pub fn attach_file_to_row(
+        &self,
+        sheet_id: u64,
+        row_id: u64,
+        file_name: &str,
+        content_type: &str,
+        data: impl Into<Vec<u8>>,
+    ) -> Result<ItemResult<AttachmentMeta>> {
+        self.rt.block_on(self.api.attach_file_to_row(
+            sheet_id,
+            row_id,
+            file_name,
+            content_type,
+            data,
+        ))
+    }
+
+    /// **Attach URL to Sheet** - Blocking version of [`SmartsheetApi::attach_url_to_sheet`](crate::SmartsheetApi::attach_url_to_sheet).
+    pub fn attach_url_to_sheet(
+        &self,
+        sheet_id: u64,
+        attachment: &UrlAttachment,
+    ) -> Result<ItemResult<AttachmentMeta>> {
+        self.rt
+            .block_on(self.api.attach_url_to_sheet(sheet_id, attachment))
+    }
+
+    /// **Attach URL to Row** - Blocking version of [`SmartsheetApi::attach_url_to_row`](crate::SmartsheetApi::attach_url_to_row).
+    pub fn attach_url_to_row(
+        &self,
+        sheet_id: u64,
+        row_id: u64,
+        attachment: &UrlAttachment,
+    ) -> Result<ItemResult<AttachmentMeta>> {
+        self.rt
+            .block_on(self.api.attach_url_to_row(sheet_id, row_id, attachment))
+    }
+
+    /// **Get Sheet Summary** - Blocking version of [`SmartsheetApi::get_sheet_summary`](crate::SmartsheetApi::get_sheet_summary).
+    pub fn get_sheet_summary(&self, sheet_id: u64) -> Result<SheetSummary> {
+        self.rt.block_on(self.api.get_sheet_summary(sheet_id))
+    }
+
+    /// **Get Sheet Summary** - Blocking version of [`SmartsheetApi::get_sheet_summary_with_params`](crate::SmartsheetApi::get_sheet_summary_with_params).
+    pub fn get_sheet_summary_with_params(
+        &self,
+        sheet_id: u64,
+        include: impl Into<Option<Vec<SummaryFieldIncludeFlags>>>,
+        exclude: impl Into<Option<Vec<SummaryFieldExcludeFlags>>>,
+    ) -> Result<SheetSummary> {
+        self.rt.block_on(
+            self.api
+                .get_sheet_summary_with_params(sheet_id, include, exclude),
+        )
+    }
+
+    /// **List Summary Fields** - Blocking version of [`SmartsheetApi::list_summary_fields`](crate::SmartsheetApi::list_summary_fields).
+    pub fn list_summary_fields(&self, sheet_id: u64) -> Result<IndexResult<SummaryField>> {
+        self.rt.block_on(self.api.list_summary_fields(sheet_id))
+    }
+
+    /// **List Summary Fields** - Blocking version of [`SmartsheetApi::list_summary_fields_with_params`](crate::SmartsheetApi::list_summary_fields_with_params).
+    pub fn list_summary_fields_with_params(
+        &self,
+        sheet_id: u64,
+        include: impl Into<Option<Vec<SummaryFieldIncludeFlags>>>,
+        exclude: impl Into<Option<Vec<SummaryFieldExcludeFlags>>>,
+        include_all: impl Into<Option<bool>>,
+    ) -> Result<IndexResult<SummaryField>> {
+        self.rt.block_on(self.api.list_summary_fields_with_params(
+            sheet_id,
+            include,
+            exclude,
+            include_all,
+        ))
+    }
+
+    /// **Add Summary Fields** - Blocking version of [`SmartsheetApi::add_summary_fields`](crate::SmartsheetApi::add_summary_fields).
+    pub fn add_summary_fields(
+        &self,
+        sheet_id: u64,
+        fields: impl Into<Vec<SummaryField>>,
+    ) -> Result<RowResult<SummaryField>> {
+        self.rt
+            .block_on(self.api.add_summary_fields(sheet_id, fields))
+    }
+
+    /// **Add Summary Fields** - Blocking version of [`SmartsheetApi::add_summary_fields_with_params`](crate::SmartsheetApi::add_summary_fields_with_params).
+    pub fn add_summary_fields_with_params(
+        &self,
+        sheet_id: u64,
+        fields: impl Into<Vec<SummaryField>>,
+        rename_if_conflict: impl Into<Option<bool>>,
+    ) -> Result<RowResult<SummaryField>> {
+        self.rt.block_on(self.api.add_summary_fields_with_params(
+            sheet_id,
+            fields,
+            rename_if_conflict,
+        ))
+    }
+
+    /// **Update Summary Fields** - Blocking version of [`SmartsheetApi::update_summary_fields`](crate::SmartsheetApi::update_summary_fields).
+    pub fn update_summary_fields(
+        &self,
+        sheet_id: u64,
+        fields: impl Into<Vec<SummaryField>>,
+    ) -> Result<RowResult<SummaryField>> {
+        self.rt
+            .block_on(self.api.update_summary_fields(sheet_id, fields))
+    }
+
+    /// **Update Summary Fields** - Blocking version of [`SmartsheetApi::update_summary_fields_with_params`](crate::SmartsheetApi::update_summary_fields_with_params).
+    pub fn update_summary_fields_with_params(
+        &self,
+        sheet_id: u64,
+        fields: impl Into<Vec<SummaryField>>,
+        rename_if_conflict: impl Into<Option<bool>>,
+    ) -> Result<RowResult<SummaryField>> {
+        self.rt.block_on(self.api.update_summary_fields_with_params(
+            sheet_id,
+            fields,
+            rename_if_conflict,
+        ))
+    }
+
+    /// **Delete Summary Fields** - Blocking version of [`SmartsheetApi::delete_summary_fields`](crate::SmartsheetApi::delete_summary_fields).
+    pub fn delete_summary_fields<const N: usize>(
+        &self,
+        sheet_id: u64,
+        field_ids: impl Into<[u64; N]>,
+    ) -> Result<RowResult<u64>> {
+        self.rt
+            .block_on(self.api.delete_summary_fields(sheet_id, field_ids))
+    }
+
+    /// **Delete Summary Fields** - Blocking version of [`SmartsheetApi::delete_summary_fields_with_params`](crate::SmartsheetApi::delete_summary_fields_with_params).
+    pub fn delete_summary_fields_with_params<const N: usize>(
+        &self,
+        sheet_id: u64,
+        field_ids: impl Into<[u64; N]>,
+        ignore_summary_fields_not_found: impl Into<Option<bool>>,
+    ) -> Result<RowResult<u64>> {
+        self.rt.block_on(self.api.delete_summary_fields_with_params(
+            sheet_id,
+            field_ids,
+            ignore_summary_fields_not_found,
+        ))
+    }
+
+    /// **List Cross-sheet References** - Blocking version of [`SmartsheetApi::list_cross_sheet_references`](crate::SmartsheetApi::list_cross_sheet_references).
+    pub fn list_cross_sheet_references(
+        &self,
+        sheet_id: u64,
+    ) -> Result<IndexResult<CrossSheetReference>> {
+        self.rt
+            .block_on(self.api.list_cross_sheet_references(sheet_id))
+    }
+
+    /// **List Cross-sheet References** - Blocking version of [`SmartsheetApi::list_cross_sheet_references_with_params`](crate::SmartsheetApi::list_cross_sheet_references_with_params).
+    pub fn list_cross_sheet_references_with_params(
+        &self,
+        sheet_id: u64,
+        include_all: impl Into<Option<bool>>,
+    ) -> Result<IndexResult<CrossSheetReference>> {
+        self.rt.block_on(
+            self.api
+                .list_cross_sheet_references_with_params(sheet_id, include_all),
+        )
+    }
+
+    /// **Get Cross-sheet Reference** - Blocking version of [`SmartsheetApi::get_cross_sheet_reference`](crate::SmartsheetApi::get_cross_sheet_reference).
+    pub fn get_cross_sheet_reference(
+        &self,
+        sheet_id: u64,
+        reference_id: u64,
+    ) -> Result<CrossSheetReference> {
+        self.rt
+            .block_on(self.api.get_cross_sheet_reference(sheet_id, reference_id))
+    }
+
+    /// **Create Cross-sheet Reference** - Blocking version of [`SmartsheetApi::create_cross_sheet_reference`](crate::SmartsheetApi::create_cross_sheet_reference).
+    pub fn create_cross_sheet_reference(
+        &self,
+        sheet_id: u64,
+        reference: &CrossSheetReference,
+    ) -> Result<ItemResult<CrossSheetReference>> {
+        self.rt
+            .block_on(self.api.create_cross_sheet_reference(sheet_id, reference))
+    }
+
+    /// **Create Update Request** - Blocking version of [`SmartsheetApi::create_update_request`](crate::SmartsheetApi::create_update_request).
+    pub fn create_update_request(
+        &self,
+        sheet_id: u64,
+        update_request: &UpdateRequest,
+    ) -> Result<ItemResult<UpdateRequest>> {
+        self.rt
+            .block_on(self.api.create_update_request(sheet_id, update_request))
+    }
+
+    /// **List Update Requests** - Blocking version of [`SmartsheetApi::list_update_requests`](crate::SmartsheetApi::list_update_requests).
+    pub fn list_update_requests(&self, sheet_id: u64) -> Result<IndexResult<UpdateRequest>> {
+        self.rt.block_on(self.api.list_update_requests(sheet_id))
+    }
+
+    /// **List Update Requests** - Blocking version of [`SmartsheetApi::list_update_requests_with_params`](crate::SmartsheetApi::list_update_requests_with_params).
+    pub fn list_update_requests_with_params(
+        &self,
+        sheet_id: u64,
+        include_all: impl Into<Option<bool>>,
+    ) -> Result<IndexResult<UpdateRequest>> {
+        self.rt.block_on(
+            self.api
+                .list_update_requests_with_params(sheet_id, include_all),
+        )
+    }
+
+    /// **Get Update Request** - Blocking version of [`SmartsheetApi::get_update_request`](crate::SmartsheetApi::get_update_request).
+    pub fn get_update_request(
+        &self,
+        sheet_id: u64,
+        update_request_id: u64,
+    ) -> Result<UpdateRequest> {
+        self.rt
+            .block_on(self.api.get_update_request(sheet_id, update_request_id))
+    }
+
+    /// **Delete Update Request** - Blocking version of [`SmartsheetApi::delete_update_request`](crate::SmartsheetApi::delete_update_request).
+    pub fn delete_update_request(
+        &self,
+        sheet_id: u64,
+        update_request_id: u64,
+    ) -> Result<EmptyResult> {
+        self.rt
+            .block_on(self.api.delete_update_request(sheet_id, update_request_id))
+    }
+
+    /// **List Sent Update Requests** - Blocking version of [`SmartsheetApi::list_sent_update_requests`](crate::SmartsheetApi::list_sent_update_requests).
+    pub fn list_sent_update_requests(
+        &self,
+        sheet_id: u64,
+    ) -> Result<IndexResult<SentUpdateRequest>> {
+        self.rt
+            .block_on(self.api.list_sent_update_requests(sheet_id))
+    }
+
+    /// **List Sent Update Requests** - Blocking version of [`SmartsheetApi::list_sent_update_requests_with_params`](crate::SmartsheetApi::list_sent_update_requests_with_params).
+    pub fn list_sent_update_requests_with_params(
+        &self,
+        sheet_id: u64,
+        include_all: impl Into<Option<bool>>,
+    ) -> Result<IndexResult<SentUpdateRequest>> {
+        self.rt.block_on(
+            self.api
+                .list_sent_update_requests_with_params(sheet_id, include_all),
+        )
+    }
+
+    /// **Get Sent Update Request** - Blocking version of [`SmartsheetApi::get_sent_update_request`](crate::SmartsheetApi::get_sent_update_request).
+    pub fn get_sent_update_request(
+        &self,
+        sheet_id: u64,
+        sent_update_request_id: u64,
+    ) -> Result<SentUpdateRequest> {
+        self.rt.block_on(
+            self.api
+                .get_sent_update_request(sheet_id, sent_update_request_id),
+        )
+    }
+
+    /// **List Automation Rules** - Blocking version of [`SmartsheetApi::list_automation_rules`](crate::SmartsheetApi::list_automation_rules).
+    pub fn list_automation_rules(&self, sheet_id: u64) -> Result<IndexResult<AutomationRule>> {
+        self.rt.block_on(self.api.list_automation_rules(sheet_id))
+    }
+
+    /// **List Automation Rules** - Blocking version of [`SmartsheetApi::list_automation_rules_with_params`](crate::SmartsheetApi::list_automation_rules_with_params).
+    pub fn list_automation_rules_with_params(
+        &self,
+        sheet_id: u64,
+        include_all: impl Into<Option<bool>>,
+    ) -> Result<IndexResult<AutomationRule>> {
+        self.rt.block_on(
+            self.api
+                .list_automation_rules_with_params(sheet_id, include_all),
+        )
+    }
+
+    /// **Get Automation Rule** - Blocking version of [`SmartsheetApi::get_automation_rule`](crate::SmartsheetApi::get_automation_rule).
+    pub fn get_automation_rule(&self, sheet_id: u64, rule_id: u64) -> Result<AutomationRule> {
+        self.rt
+            .block_on(self.api.get_automation_rule(sheet_id, rule_id))
+    }
+
+    /// **Update Automation Rule** - Blocking version of [`SmartsheetApi::update_automation_rule`](crate::SmartsheetApi::update_automation_rule).
+    pub fn update_automation_rule(
+        &self,
+        sheet_id: u64,
+        rule_id: u64,
+        rule: &AutomationRule,
+    ) -> Result<ItemResult<AutomationRule>> {
+        self.rt
+            .block_on(self.api.update_automation_rule(sheet_id, rule_id, rule))
+    }
+
+    /// **Delete Automation Rule** - Blocking version of [`SmartsheetApi::delete_automation_rule`](crate::SmartsheetApi::delete_automation_rule).
+    pub fn delete_automation_rule(&self, sheet_id: u64, rule_id: u64) -> Result<EmptyResult> {
+        self.rt
+            .block_on(self.api.delete_automation_rule(sheet_id, rule_id))
+    }
+
+    /// **List Proofs** - Blocking version of [`SmartsheetApi::list_proofs`](crate::SmartsheetApi::list_proofs).
+    pub fn list_proofs(&self, sheet_id: u64) -> Result<IndexResult<Proof>> {
+        self.rt.block_on(self.api.list_proofs(sheet_id))
+    }
+
+    /// **List Proofs** - Blocking version of [`SmartsheetApi::list_proofs_with_params`](crate::SmartsheetApi::list_proofs_with_params).
+    pub fn list_proofs_with_params(
+        &self,
+        sheet_id: u64,
+        include_all: impl Into<Option<bool>>,
+    ) -> Result<IndexResult<Proof>> {
+        self.rt
+            .block_on(self.api.list_proofs_with_params(sheet_id, include_all))
+    }
+
+    /// **Get Proof** - Blocking version of [`SmartsheetApi::get_proof`](crate::SmartsheetApi::get_proof).
+    pub fn get_proof(&self, sheet_id: u64, proof_id: u64) -> Result<Proof> {
+        self.rt.block_on(self.api.get_proof(sheet_id, proof_id))
+    }
+
+    /// **Get Proof** - Blocking version of [`SmartsheetApi::get_proof_with_params`](crate::SmartsheetApi::get_proof_with_params).
+    pub fn get_proof_with_params(
+        &self,
+        sheet_id: u64,
+        proof_id: u64,
+        include: impl Into<Option<Vec<ProofIncludeFlags>>>,
+    ) -> Result<Proof> {
+        self.rt
+            .block_on(self.api.get_proof_with_params(sheet_id, proof_id, include))
+    }
+
+    /// **Create Proof** - Blocking version of [`SmartsheetApi::create_proof`](crate::SmartsheetApi::create_proof).
+    pub fn create_proof(
+        &self,
+        sheet_id: u64,
+        row_id: u64,
+        file_name: &str,
+        content_type: &str,
+        data: impl Into<Vec<u8>>,
+    ) -> Result<ItemResult<Proof>> {
+        self.rt.block_on(
+            self.api
+                .create_proof(sheet_id, row_id, file_name, content_type, data),
+        )
+    }
+
+    /// **Update Proof Status** - Blocking version of [`SmartsheetApi::update_proof_status`](crate::SmartsheetApi::update_proof_status).
+    pub fn update_proof_status(
+        &self,
+        sheet_id: u64,
+        proof_id: u64,
+        is_completed: bool,
+    ) -> Result<ItemResult<Proof>> {
+        self.rt.block_on(
+            self.api
+                .update_proof_status(sheet_id, proof_id, is_completed),
+        )
+    }
+
+    /// **Delete Proof** - Blocking version of [`SmartsheetApi::delete_proof`](crate::SmartsheetApi::delete_proof).
+    pub fn delete_proof(&self, sheet_id: u64, proof_id: u64) -> Result<EmptyResult> {
+        self.rt.block_on(self.api.delete_proof(sheet_id, proof_id))
+    }
+
+    /// **List Proof Versions** - Blocking version of [`SmartsheetApi::list_proof_versions`](crate::SmartsheetApi::list_proof_versions).
+    pub fn list_proof_versions(&self, sheet_id: u64, proof_id: u64) -> Result<IndexResult<Proof>> {
+        self.rt
+            .block_on(self.api.list_proof_versions(sheet_id, proof_id))
+    }
+
+    /// **Create Proof Version** - Blocking version of [`SmartsheetApi::create_proof_version`](crate::SmartsheetApi::create_proof_version).
+    pub fn create_proof_version(
+        &self,
+        sheet_id: u64,
+        proof_id: u64,
+        file_name: &str,
+        content_type: &str,
+        data: impl Into<Vec<u8>>,
+    ) -> Result<ItemResult<Proof>> {
+        self.rt.block_on(self.api.create_proof_version(
+            sheet_id,
+            proof_id,
+            file_name,
+            content_type,
+            data,
+        ))
+    }
+
+    /// **Delete Proof Version** - Blocking version of [`SmartsheetApi::delete_proof_version`](crate::SmartsheetApi::delete_proof_version).
+    pub fn delete_proof_version(&self, sheet_id: u64, proof_id: u64) -> Result<EmptyResult> {
+        self.rt
+            .block_on(self.api.delete_proof_version(sheet_id, proof_id))
+    }
+
+    /// **Create Proof Request** - Blocking version of [`SmartsheetApi::create_proof_request`](crate::SmartsheetApi::create_proof_request).
+    pub fn create_proof_request(
+        &self,
+        sheet_id: u64,
+        proof_id: u64,
+        request: &ProofRequest,
+    ) -> Result<ItemResult<ProofRequest>> {
+        self.rt
+            .block_on(self.api.create_proof_request(sheet_id, proof_id, request))
+    }
+
+    /// **Delete Proof Requests** - Blocking version of [`SmartsheetApi::delete_proof_requests`](crate::SmartsheetApi::delete_proof_requests).
+    pub fn delete_proof_requests(&self, sheet_id: u64, proof_id: u64) -> Result<EmptyResult> {
+        self.rt
+            .block_on(self.api.delete_proof_requests(sheet_id, proof_id))
+    }
+
+    /// **List Proof Request Actions** - Blocking version of [`SmartsheetApi::list_proof_request_actions`](crate::SmartsheetApi::list_proof_request_actions).
+    pub fn list_proof_request_actions(
+        &self,
+        sheet_id: u64,
+        proof_id: u64,
+    ) -> Result<IndexResult<ProofRequestAction>> {
+        self.rt
+            .block_on(self.api.list_proof_request_actions(sheet_id, proof_id))
+    }
+
+    /// **Get Sheet Publish Status** - Blocking version of [`SmartsheetApi::get_sheet_publish_status`](crate::SmartsheetApi::get_sheet_publish_status).
+    pub fn get_sheet_publish_status(&self, sheet_id: u64) -> Result<SheetPublish> {
+        self.rt
+            .block_on(self.api.get_sheet_publish_status(sheet_id))
+    }
+
+    /// **Set Sheet Publish Status** - Blocking version of [`SmartsheetApi::set_sheet_publish_status`](crate::SmartsheetApi::set_sheet_publish_status).
+    pub fn set_sheet_publish_status(
+        &self,
+        sheet_id: u64,
+        status: &SheetPublish,
+    ) -> Result<ItemResult<SheetPublish>> {
+        self.rt
+            .block_on(self.api.set_sheet_publish_status(sheet_id, status))
+    }
+
+    /// **Add Image to Cell** - Blocking version of [`SmartsheetApi::add_image_to_cell`](crate::SmartsheetApi::add_image_to_cell).
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_image_to_cell<'a>(
+        &self,
+        sheet_id: u64,
+        row_id: u64,
+        column_id: u64,
+        file_name: &str,
+        content_type: &str,
+        data: impl Into<Vec<u8>>,
+        alt_text: impl Into<Option<&'a str>>,
+    ) -> Result<ItemResult<Row>> {
+        self.rt.block_on(self.api.add_image_to_cell(
+            sheet_id,
+            row_id,
+            column_id,
+            file_name,
+            content_type,
+            data,
+            alt_text,
+        ))
+    }
+
+    /// **List Image URLs** - Blocking version of [`SmartsheetApi::get_image_urls`](crate::SmartsheetApi::get_image_urls).
+    pub fn get_image_urls(&self, image_urls: impl Into<Vec<ImageUrl>>) -> Result<ImageUrlMap> {
+        self.rt.block_on(self.api.get_image_urls(image_urls))
+    }
+
+    /// **List Image URLs** - Blocking version of [`SmartsheetApi::get_sheet_image_urls`](crate::SmartsheetApi::get_sheet_image_urls).
+    pub fn get_sheet_image_urls(
+        &self,
+        sheet: &Sheet,
+        width: impl Into<Option<u64>>,
+        height: impl Into<Option<u64>>,
+    ) -> Result<ImageUrlMap> {
+        self.rt
+            .block_on(self.api.get_sheet_image_urls(sheet, width, height))
+    }
+
+    /// **List Public Templates** - Blocking version of [`SmartsheetApi::list_public_templates`](crate::SmartsheetApi::list_public_templates).
+    pub fn list_public_templates(&self) -> Result<IndexResult<Template>> {
+        self.rt.block_on(self.api.list_public_templates())
+    }
+
+    /// **List Public Templates** - Blocking version of [`SmartsheetApi::list_public_templates_with_params`](crate::SmartsheetApi::list_public_templates_with_params).
+    pub fn list_public_templates_with_params(
+        &self,
+        include_all: impl Into<Option<bool>>,
+    ) -> Result<IndexResult<Template>> {
+        self.rt
+            .block_on(self.api.list_public_templates_with_params(include_all))
+    }
+
+    /// **List User-Created Templates** - Blocking version of [`SmartsheetApi::list_user_templates`](crate::SmartsheetApi::list_user_templates).
+    pub fn list_user_templates(&self) -> Result<IndexResult<Template>> {
+        self.rt.block_on(self.api.list_user_templates())
+    }
+
+    /// **List User-Created Templates** - Blocking version of [`SmartsheetApi::list_user_templates_with_params`](crate::SmartsheetApi::list_user_templates_with_params).
+    pub fn list_user_templates_with_params(
+        &self,
+        include_all: impl Into<Option<bool>>,
+    ) -> Result<IndexResult<Template>> {
+        self.rt
+            .block_on(self.api.list_user_templates_with_params(include_all))
+    }
+
+    /// **Create Sheet from Template** - Blocking version of [`SmartsheetApi::create_sheet_from_template`](crate::SmartsheetApi::create_sheet_from_template).
+    pub fn create_sheet_from_template(
+        &self,
+        sheet: &SheetFromTemplate,
+    ) -> Result<ItemResult<Sheet>> {
+        self.rt.block_on(self.api.create_sheet_from_template(sheet))
+    }
+
+    /// **Create Sheet from Template** - Blocking version of [`SmartsheetApi::create_sheet_from_template_with_params`](crate::SmartsheetApi::create_sheet_from_template_with_params).
+    pub fn create_sheet_from_template_with_params(
+        &self,
+        sheet: &SheetFromTemplate,
+        include: impl Into<Option<Vec<SheetFromTemplateIncludeFlags>>>,
+    ) -> Result<ItemResult<Sheet>> {
+        self.rt.block_on(
+            self.api
+                .create_sheet_from_template_with_params(sheet, include),
+        )
+    }
+
+    /// **List Favorites** - Blocking version of [`SmartsheetApi::list_favorites`](crate::SmartsheetApi::list_favorites).
+    pub fn list_favorites(&self) -> Result<IndexResult<Favorite>> {
+        self.rt.block_on(self.api.list_favorites())
+    }
+
+    /// **List Favorites** - Blocking version of [`SmartsheetApi::list_favorites_with_params`](crate::SmartsheetApi::list_favorites_with_params).
+    pub fn list_favorites_with_params(
+        &self,
+        include_all: impl Into<Option<bool>>,
+    ) -> Result<IndexResult<Favorite>> {
+        self.rt
+            .block_on(self.api.list_favorites_with_params(include_all))
+    }
+
+    /// **Add Favorites** - Blocking version of [`SmartsheetApi::add_favorites`](crate::SmartsheetApi::add_favorites).
+    pub fn add_favorites(
+        &self,
+        favorites: impl Into<Vec<Favorite>>,
+    ) -> Result<RowResult<Favorite>> {
+        self.rt.block_on(self.api.add_favorites(favorites))
+    }
+
+    /// **Delete Favorite** - Blocking version of [`SmartsheetApi::remove_favorite`](crate::SmartsheetApi::remove_favorite).
+    pub fn remove_favorite(
+        &self,
+        favorite_type: FavoriteType,
+        object_id: u64,
+    ) -> Result<EmptyResult> {
+        self.rt
+            .block_on(self.api.remove_favorite(favorite_type, object_id))
+    }
+
+    /// **Delete Multiple Favorites** - Blocking version of [`SmartsheetApi::remove_favorites`](crate::SmartsheetApi::remove_favorites).
+    pub fn remove_favorites<const N: usize>(
+        &self,
+        favorite_type: FavoriteType,
+        object_ids: impl Into<[u64; N]>,
+    ) -> Result<EmptyResult> {
+        self.rt
+            .block_on(self.api.remove_favorites(favorite_type, object_ids))
+    }
+
+    /// **Get Server Info** - Blocking version of [`SmartsheetApi::get_server_info`](crate::SmartsheetApi::get_server_info).
+    pub fn get_server_info(&self) -> Result<ServerInfo> {
+        self.rt.block_on(self.api.get_server_info())
+    }
+
+    /// **Get Sheet By Name** - Blocking version of [`SmartsheetApi::get_sheet_by_name`](crate::SmartsheetApi::get_sheet_by_name).
+    #[deprecated(
+        since = "0.2.0",
+        note = "please cache the sheet id and use `get_sheet` instead"
+    )]
+    #[allow(deprecated)]
+    pub fn get_sheet_by_name(&self, sheet_name: &str) -> Result<Sheet> {
+        self.rt.block_on(self.api.get_sheet_by_name(sheet_name))
+    }
+
+    /// **Get Column By Title** - Blocking version of [`SmartsheetApi::get_column_by_title`](crate::SmartsheetApi::get_column_by_title).
+    #[deprecated(
+        since = "0.2.0",
+        note = "please cache the column id and use `get_column` instead"
+    )]
+    #[allow(deprecated)]
+    pub fn get_column_by_title(&self, sheet_id: u64, column_title: &str) -> Result<Column> {
+        self.rt
+            .block_on(self.api.get_column_by_title(sheet_id, column_title))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::MockServer;
+
+    use hyper::{Method, StatusCode};
+
+    #[test]
+    fn test_list_sheets() {
+        let server = MockServer::start(vec![(
+            200,
+            r#"{"pageNumber": 1, "totalCount": 1, "totalPages": 1, "data": [{"id": 123, "name": "My Sheet", "accessLevel": "OWNER", "permalink": "https://a.b/c"}]}"#,
+        )]);
+
+        let endpoint = server.url("");
+        let smart = SmartsheetApi::from_endpoint_and_token(&endpoint, "my-token");
+
+        let sheets = smart.list_sheets().unwrap();
+        assert_eq!(sheets.total_count, 1);
+        assert_eq!(sheets.data[0].name, "My Sheet");
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("GET /sheets "));
+        assert!(requests[0].contains("authorization: Bearer my-token"));
+    }
+
+    #[test]
+    fn test_with_meta_and_export() {
+        let server = MockServer::start(vec![(200, "a,b\n1,2\n"), (200, "a,b\n1,2\n")]);
+
+        let endpoint = server.url("");
+        let smart = SmartsheetApi::from_endpoint_and_token(&endpoint, "my-token");
+
+        let data = smart.export_sheet(123, ExportFormat::Csv).unwrap();
+        assert_eq!(&data[..], b"a,b\n1,2\n");
+
+        let res = smart
+            .with_meta(|api| api.export_sheet(123, ExportFormat::Csv))
+            .unwrap();
+
+        assert_eq!(res.meta.method, Method::GET);
+        assert_eq!(res.meta.url, server.url("/sheets/123"));
+        assert_eq!(res.meta.status, StatusCode::OK);
+    }
+}
@@ -3,6 +3,12 @@
 /// Base API endpoint for Smartsheet
 pub const API_ENDPOINT: &str = "https://api.smartsheet.com/2.0";
 
+/// Base API endpoint for Smartsheet EU (`smartsheet.eu`)
+pub const API_ENDPOINT_EU: &str = "https://api.smartsheet.eu/2.0";
+
+/// Base API endpoint for Smartsheetgov (`smartsheetgov.com`)
+pub const API_ENDPOINT_GOV: &str = "https://api.smartsheetgov.com/2.0";
+
 /// Environment variable to be used to retrieve the API token,
 /// when `SmartsheetApi::fromenv()` is invoked.
 ///
@@ -13,3 +19,49 @@ pub const API_ENDPOINT: &str = "https://api.smartsheet.com/2.0";
 ///    https://github.com/smartsheet-platform/smartsheet-python-sdk#getting-started
 ///
 pub const ENV_VAR_NAME: &str = "SMARTSHEET_ACCESS_TOKEN";
+
+/// Environment variable to be used to retrieve the `Region` - one of `US`,
+/// `EU`, or `GOV` - when `SmartsheetApi::from_env()` is invoked. If not
+/// set, this defaults to `US`.
+pub const REGION_ENV_VAR_NAME: &str = "SMARTSHEET_REGION";
+
+/// URL to direct the user to, in order to authorize a third-party app
+/// with the OAuth 2.0 *authorization code* flow.
+pub const OAUTH_AUTHORIZE_URL: &str = "https://app.smartsheet.com/b/authorize";
+
+/// URL to obtain (or refresh) an OAuth 2.0 access token.
+pub const OAUTH_TOKEN_URL: &str = "https://api.smartsheet.com/2.0/token";
+
+/// Smartsheet error code which indicates that an access token has expired.
+///
+/// See also:
+///    https://smartsheet.redoc.ly/#section/Error-Codes
+///
+pub const EXPIRED_TOKEN_ERROR_CODE: u16 = 1003;
+
+/// Smartsheet error code which indicates that an access token is invalid,
+/// for instance because it was revoked or rotated.
+///
+/// See also:
+///    https://smartsheet.redoc.ly/#section/Error-Codes
+///
+pub const INVALID_TOKEN_ERROR_CODE: u16 = 1002;
+
+/// Header used by an admin to make a request on behalf of another user.
+///
+/// See also:
+///    https://smartsheet.redoc.ly/#section/Work-at-Scale/Assume-User
+///
+pub const ASSUME_USER_HEADER: &str = "Assume-User";
+
+/// Header used to identify the integration which made a change, which is
+/// included in the *Sheet Change* events of webhook callbacks.
+pub const CHANGE_AGENT_HEADER: &str = "Smartsheet-Change-Agent";
+
+/// Environment variables to retrieve the URL of an HTTP proxy from, in
+/// order of precedence.
+pub const HTTPS_PROXY_ENV_VAR_NAMES: &[&str] = &["HTTPS_PROXY", "https_proxy"];
+
+/// Environment variables to retrieve the (comma-separated) hosts which
+/// bypass the HTTP proxy from, in order of precedence.
+pub const NO_PROXY_ENV_VAR_NAMES: &[&str] = &["NO_PROXY", "no_proxy"];
@@ -0,0 +1,353 @@
+//! Decompression of response data, which is enabled with the `gzip`,
+//! `deflate`, and `brotli` features.
+//!
+//! When any of these features are enabled, the client sends an
+//! `Accept-Encoding` header with each request, and the Smartsheet API can
+//! then respond with compressed data - which is a lot smaller for large
+//! sheets. The data is decompressed as it is received, on a blocking
+//! thread of the Tokio runtime, before the response is deserialized.
+//!
+#![cfg_attr(
+    not(any(feature = "gzip", feature = "deflate", feature = "brotli")),
+    allow(unused_imports, unused_mut, unused_variables, unreachable_code)
+)]
+
+use crate::types::Result;
+
+use std::io::{self, BufRead, ErrorKind, Read};
+
+use hyper::body::{Bytes, HttpBody, Sender};
+use hyper::header::{HeaderValue, CONTENT_ENCODING, CONTENT_LENGTH};
+use hyper::{Body, Response};
+#[cfg(any(feature = "gzip", feature = "deflate", feature = "brotli"))]
+use tokio::runtime::Handle;
+
+/// The size of each chunk of decompressed data.
+#[cfg(any(feature = "gzip", feature = "deflate", feature = "brotli"))]
+const CHUNK_SIZE: usize = 16 * 1024;
+
+/// Returns the value of the `Accept-Encoding` header to send with each
+/// request, or `None` if decompression is not enabled.
+pub(crate) fn accept_encoding() -> Option<HeaderValue> {
+    let mut encodings: Vec<&str> = Vec::new();
+
+    #[cfg(feature = "gzip")]
+    encodings.push("gzip");
+    #[cfg(feature = "deflate")]
+    encodings.push("deflate");
+    #[cfg(feature = "brotli")]
+    encodings.push("br");
+
+    if encodings.is_empty() {
+        None
+    } else {
+        HeaderValue::from_str(&encodings.join(", ")).ok()
+    }
+}
+
+/// Returns the response unchanged, as decompression is not enabled.
+#[cfg(not(any(feature = "gzip", feature = "deflate", feature = "brotli")))]
+pub(crate) async fn decompress(res: Response<Body>) -> Result<Response<Body>> {
+    Ok(res)
+}
+
+/// Decompress the body of a response, if it is compressed with one of the
+/// enabled encodings.
+///
+/// The body is decompressed as it is read, so that the compressed and the
+/// decompressed data are never held in memory at once. The
+/// `Content-Encoding` and `Content-Length` headers are then removed.
+#[cfg(any(feature = "gzip", feature = "deflate", feature = "brotli"))]
+pub(crate) async fn decompress(res: Response<Body>) -> Result<Response<Body>> {
+    let encoding = match res.headers().get(CONTENT_ENCODING) {
+        Some(value) => value
+            .to_str()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase(),
+        None => return Ok(res),
+    };
+
+    let decoder: fn(BodyReader) -> io::Result<Box<dyn Read>> = match encoding.as_str() {
+        #[cfg(feature = "gzip")]
+        "gzip" | "x-gzip" => gunzip,
+        #[cfg(feature = "deflate")]
+        "deflate" => inflate,
+        #[cfg(feature = "brotli")]
+        "br" => unbrotli,
+        _ => return Ok(res),
+    };
+
+    let (mut parts, body) = res.into_parts();
+    parts.headers.remove(CONTENT_ENCODING);
+    parts.headers.remove(CONTENT_LENGTH);
+
+    let (sender, decompressed) = Body::channel();
+
+    // The decoders are synchronous, so they are run on a blocking thread
+    // rather than on the executor.
+    let handle = Handle::current();
+    tokio::task::spawn_blocking(move || {
+        let reader = BodyReader::new(body, handle.clone());
+        send_decoded(decoder(reader), sender, &handle);
+    });
+
+    Ok(Response::from_parts(parts, decompressed))
+}
+
+/// Decompress `gzip` data.
+#[cfg(feature = "gzip")]
+fn gunzip(reader: BodyReader) -> io::Result<Box<dyn Read>> {
+    Ok(Box::new(flate2::bufread::GzDecoder::new(reader)))
+}
+
+/// Decompress `deflate` data.
+#[cfg(feature = "deflate")]
+fn inflate(mut reader: BodyReader) -> io::Result<Box<dyn Read>> {
+    // The data *should* be in the zlib format, but some servers send raw
+    // deflate data instead; a zlib header is a multiple of 31, and has a
+    // compression method of 8.
+    let header = reader.peek(2)?;
+    let is_zlib = header.len() >= 2
+        && header[0] & 0x0F == 8
+        && ((u16::from(header[0]) << 8) | u16::from(header[1])) % 31 == 0;
+
+    if is_zlib {
+        Ok(Box::new(flate2::bufread::ZlibDecoder::new(reader)))
+    } else {
+        Ok(Box::new(flate2::bufread::DeflateDecoder::new(reader)))
+    }
+}
+
+/// Decompress `br` (Brotli) data.
+#[cfg(feature = "brotli")]
+fn unbrotli(reader: BodyReader) -> io::Result<Box<dyn Read>> {
+    Ok(Box::new(brotli_decompressor::Decompressor::new(
+        reader, CHUNK_SIZE,
+    )))
+}
+
+/// Read the decompressed data from a `decoder`, and send it in chunks to
+/// the body of the response.
+///
+/// If the data cannot be decompressed, the body is aborted, so that reading
+/// it returns an error.
+#[cfg(any(feature = "gzip", feature = "deflate", feature = "brotli"))]
+fn send_decoded(decoder: io::Result<Box<dyn Read>>, mut sender: Sender, handle: &Handle) {
+    use crate::log::error;
+
+    let mut decoder = match decoder {
+        Ok(decoder) => decoder,
+        Err(err) => {
+            error!("Failed to decompress the response: {}", err);
+            return sender.abort();
+        }
+    };
+    let mut buf = vec![0; CHUNK_SIZE];
+
+    loop {
+        match decoder.read(&mut buf) {
+            Ok(0) => return,
+            Ok(n) => {
+                let chunk = Bytes::copy_from_slice(&buf[..n]);
+                // The response was dropped, so the rest of the data is unused
+                if handle.block_on(sender.send_data(chunk)).is_err() {
+                    return;
+                }
+            }
+            Err(err) if err.kind() == ErrorKind::Interrupted => {}
+            Err(err) => {
+                error!("Failed to decompress the response: {}", err);
+                return sender.abort();
+            }
+        }
+    }
+}
+
+/// A synchronous reader of the (compressed) body of a response, for use on
+/// a blocking thread; each chunk of the body is awaited on the runtime.
+#[cfg(any(feature = "gzip", feature = "deflate", feature = "brotli"))]
+struct BodyReader {
+    body: Body,
+    chunk: Bytes,
+    handle: Handle,
+}
+
+#[cfg(any(feature = "gzip", feature = "deflate", feature = "brotli"))]
+impl BodyReader {
+    fn new(body: Body, handle: Handle) -> Self {
+        Self {
+            body,
+            chunk: Bytes::new(),
+            handle,
+        }
+    }
+
+    /// Returns (at least) the next `n` bytes of the body, without consuming
+    /// them - or fewer, if the body ends first.
+    #[cfg(feature = "deflate")]
+    fn peek(&mut self, n: usize) -> io::Result<&[u8]> {
+        while self.chunk.len() < n {
+            let mut data = self.chunk.to_vec();
+            match self.next_chunk()? {
+                Some(chunk) => data.extend_from_slice(&chunk),
+                None => break,
+            }
+            self.chunk = Bytes::from(data);
+        }
+
+        Ok(&self.chunk)
+    }
+
+    /// Returns the next (non-empty) chunk of the body, or `None` at the end.
+    fn next_chunk(&mut self) -> io::Result<Option<Bytes>> {
+        loop {
+            match self.handle.block_on(self.body.data()) {
+                Some(Ok(chunk)) if chunk.is_empty() => continue,
+                Some(Ok(chunk)) => return Ok(Some(chunk)),
+                Some(Err(err)) => return Err(io::Error::new(ErrorKind::Other, err)),
+                None => return Ok(None),
+            }
+        }
+    }
+}
+
+#[cfg(any(feature = "gzip", feature = "deflate", feature = "brotli"))]
+impl Read for BodyReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.consume(n);
+
+        Ok(n)
+    }
+}
+
+#[cfg(any(feature = "gzip", feature = "deflate", feature = "brotli"))]
+impl BufRead for BodyReader {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.chunk.is_empty() {
+            if let Some(chunk) = self.next_chunk()? {
+                self.chunk = chunk;
+            }
+        }
+
+        Ok(&self.chunk)
+    }
+
+    fn consume(&mut self, amt: usize) {
+        let _ = self.chunk.split_to(amt);
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "gzip", feature = "deflate", feature = "brotli"))]
+mod tests {
+    use super::*;
+
+    use std::io::Write;
+
+    const DATA: &str = r#"{"id": 123, "name": "My Sheet"}"#;
+
+    fn compressed(encoding: &'static str, data: Vec<u8>) -> Response<Body> {
+        Response::builder()
+            .header(CONTENT_ENCODING, encoding)
+            .header(CONTENT_LENGTH, data.len())
+            .body(Body::from(data))
+            .unwrap()
+    }
+
+    async fn assert_decompressed(res: Response<Body>) {
+        let res = decompress(res).await.unwrap();
+
+        assert!(res.headers().get(CONTENT_ENCODING).is_none());
+        assert!(res.headers().get(CONTENT_LENGTH).is_none());
+
+        let data = hyper::body::to_bytes(res).await.unwrap();
+        assert_eq!(&data[..], DATA.as_bytes());
+    }
+
+    #[cfg(feature = "gzip")]
+    #[tokio::test]
+    async fn test_decompress_gzip() {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), Default::default());
+        encoder.write_all(DATA.as_bytes()).unwrap();
+
+        assert_decompressed(compressed("gzip", encoder.finish().unwrap())).await;
+    }
+
+    #[cfg(feature = "deflate")]
+    #[tokio::test]
+    async fn test_decompress_deflate() {
+        let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), Default::default());
+        encoder.write_all(DATA.as_bytes()).unwrap();
+
+        assert_decompressed(compressed("deflate", encoder.finish().unwrap())).await;
+
+        let mut encoder = flate2::write::DeflateEncoder::new(Vec::new(), Default::default());
+        encoder.write_all(DATA.as_bytes()).unwrap();
+
+        assert_decompressed(compressed("deflate", encoder.finish().unwrap())).await;
+    }
+
+    #[cfg(feature = "deflate")]
+    #[tokio::test]
+    async fn test_decompress_in_chunks() {
+        let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), Default::default());
+        encoder.write_all(DATA.as_bytes()).unwrap();
+        let data = encoder.finish().unwrap();
+
+        // Send the compressed data one byte at a time, so that even the
+        // zlib header is split across chunks
+        let (mut sender, body) = Body::channel();
+        tokio::spawn(async move {
+            for byte in data {
+                sender.send_data(Bytes::from(vec![byte])).await.unwrap();
+            }
+        });
+
+        let mut res = Response::new(body);
+        res.headers_mut()
+            .insert(CONTENT_ENCODING, HeaderValue::from_static("deflate"));
+
+        assert_decompressed(res).await;
+    }
+
+    #[cfg(feature = "brotli")]
+    #[tokio::test]
+    async fn test_decompress_brotli() {
+        let mut data = Vec::new();
+        {
+            let mut encoder = brotli::CompressorWriter::new(&mut data, 4096, 5, 22);
+            encoder.write_all(DATA.as_bytes()).unwrap();
+        }
+
+        assert_decompressed(compressed("br", data)).await;
+    }
+
+    #[cfg(feature = "gzip")]
+    #[tokio::test]
+    async fn test_invalid_data_is_an_error() {
+        let res = decompress(compressed("gzip", DATA.into())).await.unwrap();
+
+        assert!(hyper::body::to_bytes(res).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_encoding_is_unchanged() {
+        let res = decompress(compressed("zstd", DATA.into())).await.unwrap();
+
+        assert_eq!(res.headers()[CONTENT_ENCODING], "zstd");
+    }
+
+    #[test]
+    fn test_accept_encoding() {
+        let value = accept_encoding().unwrap();
+        let value = value.to_str().unwrap();
+
+        assert_eq!(value.contains("gzip"), cfg!(feature = "gzip"));
+        assert_eq!(value.contains("deflate"), cfg!(feature = "deflate"));
+        assert_eq!(value.contains("br"), cfg!(feature = "brotli"));
+    }
+}
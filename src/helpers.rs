@@ -57,7 +57,7 @@ impl<'a> ColumnMapper<'a> {
     }
 
     /// Retrieve the `name` <-> `id` mappings for *columns* in a sheet.
-    fn get_mappings(columns: &'a [Column]) -> (ColumnNameToId, ColumnIdToName) {
+    fn get_mappings(columns: &'a [Column]) -> (ColumnNameToId<'a>, ColumnIdToName<'a>) {
         let num_columns = columns.len();
         if num_columns == 0 {
             // TODO maybe don't panic
@@ -101,10 +101,10 @@ impl<'a> CellGetter<'a> {
         Self::new(columns)
     }
 
-    /// Create a new `CellGetter` from a reference to a mapping of *column name*
-    /// to *column id*
-    ///
-    /// NOTE: Disabling this for now, because I can't get the lifetimes to work.
+    // Create a new `CellGetter` from a reference to a mapping of *column name*
+    // to *column id*
+    //
+    // NOTE: Disabling this for now, because I can't get the lifetimes to work.
     // pub fn from_name_to_id<'b>(column_name_to_id: &'b ColumnNameToId<'b>) -> CellGetter<'a> {
     //     let mut id_to_column_name: ColumnIdToName<'b> =
     //         HashMap::with_capacity(column_name_to_id.len());
@@ -119,7 +119,7 @@ impl<'a> CellGetter<'a> {
     // }
 
     /// Find a `cell` in a `row` by its *column name*.
-    pub fn by_name<'b>(&'a self, row: &'a Row, name: &'b str) -> Result<&Cell> {
+    pub fn by_name<'b>(&'a self, row: &'a Row, name: &'b str) -> Result<&'a Cell> {
         match self.column_name_to_id.get(name) {
             Some(&col_id) => row.get_cell_by_id(col_id),
             None => Err(Box::from(Error::new(
@@ -130,7 +130,7 @@ impl<'a> CellGetter<'a> {
     }
 
     /// Find a `cell` in a `row` by its *column id*.
-    pub fn by_id(&'a self, row: &'a Row, column_id: u64) -> Result<&Cell> {
+    pub fn by_id(&'a self, row: &'a Row, column_id: u64) -> Result<&'a Cell> {
         row.get_cell_by_id(column_id)
     }
 
@@ -301,7 +301,7 @@ impl<'a> RowFinder<'a> {
     pub fn first<'b>(&'b self) -> Result<&'a Row> {
         let cmp = self.cmp.get_cell_comparator();
 
-        return match self.rows.iter().find(|row| {
+        match self.rows.iter().find(|row| {
             if let Ok(cell) = row.get_cell_by_id(self.column_id) {
                 matches!(&cell.value, Some(cv) if cmp(&self.value, cv))
             } else {
@@ -313,7 +313,7 @@ impl<'a> RowFinder<'a> {
                 ErrorKind::NotFound,
                 "No matching row for the condition",
             ))),
-        };
+        }
     }
 
     /// Find *all* `Row`s matching a specified condition.
@@ -11,9 +11,23 @@
 //!     * `rust-tls`: enables the rust implementation of TLS (default)
 //!     * `native-tls`: enables the native implementation of TLS using OpenSSL
 //!
+//! Requests are only ever sent over HTTPS, so that the access token is not
+//! sent in cleartext; plain HTTP is allowed only in the unit tests, which
+//! use local servers.
+//!
+//! The version of HTTP to use is set by the `HttpVersion` of the client,
+//! which is negotiated with the server via [ALPN][] when using `rust-tls`.
+//!
 //! [AWS Lambda]: https://docs.aws.amazon.com/sdk-for-rust/latest/dg/lambda.html
+//! [ALPN]: https://en.wikipedia.org/wiki/Application-Layer_Protocol_Negotiation
 //!
-use hyper::client::HttpConnector;
+#[cfg(not(feature = "rust-tls"))]
+use crate::log::warn;
+use crate::proxy::{Proxy, ProxyConnector};
+use crate::tls::{PinningConnector, TlsSettings};
+
+use std::sync::Arc;
+
 use hyper::Client;
 
 #[cfg(feature = "rust-tls")]
@@ -25,48 +39,176 @@ use rustls::ClientConfig;
 #[cfg(feature = "rust-tls")]
 use tls::ConfigBuilderExt;
 
-#[cfg(all(feature = "rust-tls", feature = "http2"))]
-pub(crate) fn get_https_client() -> Client<tls::HttpsConnector<HttpConnector>> {
-    // Prepare the HTTPS connector
-    let https_connector = tls::HttpsConnectorBuilder::new()
-        // .with_native_roots()
-        .with_tls_config(
-            ClientConfig::builder()
-                .with_safe_defaults()
-                .with_native_roots()
-                .with_no_client_auth(),
-        )
-        .https_only()
-        .enable_http2()
-        .build();
+/// The version of HTTP to use for requests to the Smartsheet API.
+///
+/// With the `native-tls` feature, the version is not negotiated with the
+/// server, and so requests are always sent over HTTP/1.1.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HttpVersion {
+    /// Use HTTP/2 if the server supports it, and HTTP/1.1 otherwise. This
+    /// is negotiated via ALPN as part of the TLS handshake.
+    #[default]
+    Auto,
+    /// Always use HTTP/1.1.
+    Http1,
+    /// Always use HTTP/2; requests fail if the server does not support it.
+    Http2,
+}
 
-    let mut builder = Client::builder();
-    builder.http2_only(true);
+/// The HTTPS client used to make requests.
+pub(crate) type HttpsClient = Client<PinningConnector>;
 
-    builder.build::<_, hyper::Body>(https_connector)
+/// Configuration for the connections made by an `HttpsClient`.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct HttpsConfig {
+    /// The proxy to connect via, if any.
+    pub(crate) proxy: Option<Proxy>,
+    /// The custom TLS configuration, if any.
+    pub(crate) tls: Option<TlsSettings>,
+    /// The version of HTTP to use.
+    pub(crate) version: HttpVersion,
 }
 
-#[cfg(all(feature = "rust-tls", not(feature = "http2")))]
-pub(crate) fn get_https_client() -> Client<tls::HttpsConnector<HttpConnector>> {
-    // Prepare the HTTPS connector
-    let https_connector = tls::HttpsConnectorBuilder::new()
-        // .with_native_roots()
-        .with_tls_config(
-            ClientConfig::builder()
-                .with_safe_defaults()
-                .with_native_roots()
-                .with_no_client_auth(),
-        )
-        .https_only()
-        .enable_http2()
-        .build();
-
-    Client::builder().build::<_, hyper::Body>(https_connector)
+impl HttpsConfig {
+    /// Returns the configuration from the environment - for example, the
+    /// proxy in the `HTTPS_PROXY` environment variable.
+    pub(crate) fn from_env() -> Self {
+        Self {
+            proxy: Proxy::from_env(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the certificates which are pinned, if any.
+    fn pins(&self) -> Arc<Vec<[u8; 32]>> {
+        self.tls
+            .as_ref()
+            .map(|tls| tls.pins.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// Returns the `rustls` configuration to use, which trusts the root
+/// certificates of the platform by default.
+#[cfg(feature = "rust-tls")]
+fn tls_config(config: &HttpsConfig) -> ClientConfig {
+    match &config.tls {
+        Some(tls) => {
+            let mut tls_config = tls.config.clone();
+            // The ALPN protocols are set by the HTTPS connector
+            tls_config.alpn_protocols.clear();
+            tls_config
+        }
+        None => ClientConfig::builder()
+            .with_safe_defaults()
+            .with_native_roots()
+            .with_no_client_auth(),
+    }
+}
+
+#[cfg(feature = "rust-tls")]
+pub(crate) fn build_https_client(config: &HttpsConfig) -> HttpsClient {
+    let http = ProxyConnector::new(config.proxy.clone());
+
+    // Prepare the HTTPS connector, which sets the ALPN protocols to offer
+    let builder = tls::HttpsConnectorBuilder::new().with_tls_config(tls_config(config));
+    // Plain HTTP is only allowed for the local servers in our unit tests
+    #[cfg(not(test))]
+    let builder = builder.https_only();
+    #[cfg(test)]
+    let builder = builder.https_or_http();
+    let https_connector = match config.version {
+        HttpVersion::Auto => builder.enable_http1().enable_http2().wrap_connector(http),
+        HttpVersion::Http1 => builder.enable_http1().wrap_connector(http),
+        HttpVersion::Http2 => builder.enable_http2().wrap_connector(http),
+    };
+
+    let mut builder = Client::builder();
+    builder.http2_only(config.version == HttpVersion::Http2);
+
+    builder.build::<_, hyper::Body>(PinningConnector::new(https_connector, config.pins()))
 }
 
 #[cfg(not(feature = "rust-tls"))]
-pub(crate) fn get_https_client() -> Client<tls::HttpsConnector<HttpConnector>> {
+pub(crate) fn build_https_client(config: &HttpsConfig) -> HttpsClient {
+    if config.version == HttpVersion::Http2 {
+        warn!("HTTP/2 is not supported with the `native-tls` feature; using HTTP/1.1");
+    }
+
     // Prepare the HTTPS connector
-    let https_connector = tls::HttpsConnector::new();
-    Client::builder().build::<_, hyper::Body>(https_connector)
+    let http = ProxyConnector::new(config.proxy.clone());
+    let mut https_connector = match &config.tls {
+        Some(tls) => tls::HttpsConnector::from((http, tls.connector.clone().into())),
+        None => tls::HttpsConnector::new_with_connector(http),
+    };
+    // Plain HTTP is only allowed for the local servers in our unit tests
+    https_connector.https_only(!cfg!(test));
+
+    Client::builder().build::<_, hyper::Body>(PinningConnector::new(https_connector, config.pins()))
+}
+
+#[cfg(test)]
+#[cfg(feature = "rust-tls")]
+mod tests {
+    use super::*;
+    use crate::test_utils::{VersionServer, TLS_CA_PEM};
+    use crate::tls::TlsConfig;
+    use crate::SmartsheetApi;
+
+    use hyper::Version;
+
+    const LIST_SHEETS: &str = r#"{"pageNumber": 1, "totalCount": 0, "totalPages": 1, "data": []}"#;
+
+    /// Returns the HTTP versions used by a client with `version`, for two
+    /// requests to a server which does (or doesn't) support HTTP/2.
+    async fn versions_used(version: HttpVersion, http2: bool) -> Option<Vec<Version>> {
+        let server = VersionServer::start(http2, LIST_SHEETS).await;
+
+        let tls = TlsConfig::new()
+            .add_root_certificates_pem(TLS_CA_PEM)
+            .unwrap();
+        let smart = SmartsheetApi::from_endpoint_and_token(&server.url(""), "my-token")
+            .with_proxy(None)
+            .with_tls_config(tls)
+            .unwrap()
+            .with_http_version(version);
+
+        smart.list_sheets().await.ok()?;
+        smart.list_sheets().await.ok()?;
+
+        Some(server.versions())
+    }
+
+    #[tokio::test]
+    async fn test_auto() {
+        assert_eq!(
+            versions_used(HttpVersion::Auto, true).await.unwrap(),
+            [Version::HTTP_2, Version::HTTP_2]
+        );
+        assert_eq!(
+            versions_used(HttpVersion::Auto, false).await.unwrap(),
+            [Version::HTTP_11, Version::HTTP_11]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_http1() {
+        assert_eq!(
+            versions_used(HttpVersion::Http1, true).await.unwrap(),
+            [Version::HTTP_11, Version::HTTP_11]
+        );
+        assert_eq!(
+            versions_used(HttpVersion::Http1, false).await.unwrap(),
+            [Version::HTTP_11, Version::HTTP_11]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_http2() {
+        assert_eq!(
+            versions_used(HttpVersion::Http2, true).await.unwrap(),
+            [Version::HTTP_2, Version::HTTP_2]
+        );
+        assert_eq!(versions_used(HttpVersion::Http2, false).await, None);
+    }
 }
@@ -55,6 +55,44 @@
 //! - [Add Rows](https://smartsheet-platform.github.io/api-docs/#add-rows)
 //! - [Update Rows](https://smartsheet-platform.github.io/api-docs/#update-rows)
 //! - [Delete Rows](https://smartsheet-platform.github.io/api-docs/#delete-rows)
+//! - [Get Sheet as Excel / PDF / CSV](https://smartsheet-platform.github.io/api-docs/#get-sheet-as-excel-pdf-csv)
+//! - [Get Report as Excel / CSV](https://smartsheet-platform.github.io/api-docs/#get-report-as-excel-csv)
+//! - [Get Sheet Summary](https://smartsheet.redoc.ly/#operation/get-sheet-summary)
+//! - [List Summary Fields](https://smartsheet.redoc.ly/#operation/list-summary-fields)
+//! - [Add Summary Fields](https://smartsheet.redoc.ly/#operation/add-summary-fields)
+//! - [Update Summary Fields](https://smartsheet.redoc.ly/#operation/update-summary-fields)
+//! - [Delete Summary Fields](https://smartsheet.redoc.ly/#operation/delete-summary-fields)
+//! - [List Cross-sheet References](https://smartsheet.redoc.ly/#operation/list-cross-sheet-references)
+//! - [Get Cross-sheet Reference](https://smartsheet.redoc.ly/#operation/get-cross-sheet-reference)
+//! - [Create Cross-sheet Reference](https://smartsheet.redoc.ly/#operation/create-cross-sheet-reference)
+//! - [Create Update Request](https://smartsheet.redoc.ly/#operation/updaterequests-create)
+//! - [List Update Requests](https://smartsheet.redoc.ly/#operation/updaterequests-list)
+//! - [Get Update Request](https://smartsheet.redoc.ly/#operation/updaterequests-get)
+//! - [Delete Update Request](https://smartsheet.redoc.ly/#operation/updaterequests-delete)
+//! - [List Sent Update Requests](https://smartsheet.redoc.ly/#operation/sentupdaterequests-list)
+//! - [Get Sent Update Request](https://smartsheet.redoc.ly/#operation/sentupdaterequest-get)
+//! - [List Automation Rules](https://smartsheet.redoc.ly/#operation/automationrules-list)
+//! - [Get Automation Rule](https://smartsheet.redoc.ly/#operation/automationrule-get)
+//! - [Update Automation Rule](https://smartsheet.redoc.ly/#operation/automationrule-update)
+//! - [Delete Automation Rule](https://smartsheet.redoc.ly/#operation/automationrule-delete)
+//! - [List Proofs](https://smartsheet.redoc.ly/#operation/proofs-getAllProofs)
+//! - [Get Proof](https://smartsheet.redoc.ly/#operation/proofs-get)
+//! - [Create Proof](https://smartsheet.redoc.ly/#operation/proofs-create)
+//! - [Update Proof Status](https://smartsheet.redoc.ly/#operation/proofs-update)
+//! - [Delete Proof](https://smartsheet.redoc.ly/#operation/proofs-delete)
+//! - [List / Create / Delete Proof Versions](https://smartsheet.redoc.ly/#operation/proofs-getVersions)
+//! - [Create / Delete Proof Requests](https://smartsheet.redoc.ly/#operation/proofs-createProofRequests)
+//! - [List Proof Request Actions](https://smartsheet.redoc.ly/#operation/proofs-getRequestActions)
+//! - [Add Image to Cell](https://smartsheet.redoc.ly/#operation/addImageToCell)
+//! - [List Image URLs](https://smartsheet.redoc.ly/#operation/listImageUrls)
+//! - [List Public Templates](https://smartsheet.redoc.ly/#operation/templates-listPublic)
+//! - [List User-Created Templates](https://smartsheet.redoc.ly/#operation/templates-list)
+//! - [Create Sheet from Template](https://smartsheet.redoc.ly/#operation/create-sheet-in-sheets-folder)
+//! - [List / Add / Delete Favorites](https://smartsheet.redoc.ly/#operation/getFavorites)
+//! - [Get Server Info](https://smartsheet.redoc.ly/#operation/serverinfo-get)
+//! - [Get / Set Sheet Publish Status](https://smartsheet.redoc.ly/#operation/get-sheetPublish)
+//! - [Attach File / URL to Sheet](https://smartsheet.redoc.ly/#operation/attachments-attachToSheet)
+//! - [Attach File / URL to Row](https://smartsheet.redoc.ly/#operation/row-attachments-attachFile)
 //!
 //! You can check out sample usage of these API methods in the [examples/](https://github.com/rnag/smartsheet-rs/tree/main/examples)
 //! folder in the project repo on GitHub.
@@ -91,6 +129,105 @@
 //! [`hyper-tls`]: https://docs.rs/hyper-tls
 //! [AWS Lambda]: https://docs.aws.amazon.com/sdk-for-rust/latest/dg/lambda.html
 //!
+//! ### Blocking Client
+//!
+//! For synchronous code, such as CLI scripts, enable the "blocking" feature
+//! to use `smartsheet_rs::blocking::SmartsheetApi`. It has the same methods as
+//! the async client, but they run to completion on an internal Tokio runtime:
+//!
+//! ```toml
+//! [dependencies]
+//! smartsheet-rs = { version = "0.6.2", features = ["blocking"] }
+//! ```
+//!
+//! ### Tracing
+//!
+//! Enable the "tracing" feature to open a [`tracing`][] span for each API
+//! call, with fields such as the endpoint (with IDs redacted), sheet ID, status,
+//! error code, and the network and deserialization durations:
+//!
+//! ```toml
+//! [dependencies]
+//! smartsheet-rs = { version = "0.6.2", features = ["tracing"] }
+//! ```
+//!
+//! [`tracing`]: https://docs.rs/tracing
+//!
+//! ### Compression
+//!
+//! Enable the "gzip", "deflate", or "brotli" features to request compressed
+//! responses from the Smartsheet API, which are decompressed before they are
+//! deserialized. This can greatly reduce the transfer time for large sheets:
+//!
+//! ```toml
+//! [dependencies]
+//! smartsheet-rs = { version = "0.6.2", features = ["gzip", "brotli"] }
+//! ```
+//!
+//! ### Testing
+//!
+//! Enable the "testing" feature to record the responses from the Smartsheet
+//! API to a JSON *cassette* file, and replay them in tests without an access
+//! token. Email addresses are scrubbed from the file, and the access token
+//! is never recorded:
+//!
+//! ```ignore
+//! use smartsheet_rs::testing::Cassette;
+//! use smartsheet_rs::SmartsheetApi;
+//!
+//! let cassette = Cassette::replay("tests/cassettes/sheet_and_rows.json")?;
+//! let smart = SmartsheetApi::from_token("").with_middleware(cassette);
+//! ```
+//!
+//! ### Proxy
+//!
+//! Requests can be sent via an HTTP proxy, which is set from the `HTTPS_PROXY`
+//! and `NO_PROXY` environment variables by default, or explicitly:
+//!
+//! ```ignore
+//! use smartsheet_rs::proxy::Proxy;
+//! use smartsheet_rs::SmartsheetApi;
+//!
+//! let proxy = Proxy::new("http://proxy.corp.example.com:3128")?
+//!     .with_basic_auth("user", "password")
+//!     .with_no_proxy("localhost, .internal.example.com");
+//!
+//! let smart = SmartsheetApi::from_env()?.with_proxy(proxy);
+//! ```
+//!
+//! ### TLS
+//!
+//! By default, the platform's root certificates are trusted. A `TlsConfig` can
+//! trust a private CA, pin the server certificate, or limit the TLS versions:
+//!
+//! ```ignore
+//! use smartsheet_rs::tls::{TlsConfig, TlsVersion};
+//! use smartsheet_rs::SmartsheetApi;
+//!
+//! let tls = TlsConfig::new()
+//!     .add_root_certificates_pem(&std::fs::read("gateway-ca.pem")?)?
+//!     .with_min_version(TlsVersion::Tls12);
+//!
+//! let smart = SmartsheetApi::from_env()?.with_tls_config(tls)?;
+//! ```
+//!
+//! A `rustls::ClientConfig` (with `rust-tls`) or `native_tls::TlsConnector` (with
+//! `native-tls`) can also be used directly, via `TlsConfig::from_rustls` or
+//! `TlsConfig::from_native_tls`.
+//!
+//! ### HTTP Version
+//!
+//! By default, HTTP/2 is used if the server supports it (as negotiated via ALPN),
+//! and HTTP/1.1 otherwise. This can be set explicitly with an `HttpVersion`:
+//!
+//! ```ignore
+//! use smartsheet_rs::{HttpVersion, SmartsheetApi};
+//!
+//! let smart = SmartsheetApi::from_env()?.with_http_version(HttpVersion::Http1);
+//! ```
+//!
+//! Note that with the `native-tls` feature, HTTP/1.1 is always used.
+//!
 
 // #![warn(missing_docs)]
 
@@ -98,31 +235,46 @@ mod features_check;
 
 #[cfg(feature = "logging")]
 mod log {
-    pub use log::{debug, error, trace, warn};
+    pub use log::{debug, error, warn};
 }
 
 #[cfg(not(feature = "logging"))]
 mod log {
     macro_rules! debug      ( ($($tt:tt)*) => {{}} );
     macro_rules! error      ( ($($tt:tt)*) => {{}} );
-    macro_rules! trace      ( ($($tt:tt)*) => {{}} );
     macro_rules! warning    ( ($($tt:tt)*) => {{}} );
-    pub(crate) use {debug, error, trace, warning as warn};
+    pub(crate) use {debug, error, warning as warn};
 }
 
 pub use api::SmartsheetApi;
 pub use cell_factory::CellFactory;
 pub use helpers::{CellGetter, ColumnMapper, RowGetter};
+pub use https::HttpVersion;
+pub use region::Region;
 
 mod api;
 pub mod auth;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod builders;
 mod cell_factory;
 pub mod constants;
+mod decompress;
 pub mod helpers;
 mod https;
+pub mod middleware;
 pub mod models;
+pub mod proxy;
+pub mod rate_limit;
+pub mod region;
+pub mod response;
 pub mod status;
+mod telemetry;
+#[cfg(test)]
+mod test_utils;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod tls;
 pub mod types;
 pub mod utils;
 
@@ -0,0 +1,150 @@
+//! Middleware, which can inspect or modify each request made by the client,
+//! and the response it receives.
+//!
+//! Middleware is added to a client with `SmartsheetApi::with_middleware`,
+//! and can be used to - for example - add headers, record metrics, audit
+//! requests, or serve a cached response.
+//!
+use crate::types::Result;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use hyper::{Body, Request, Response};
+
+/// A **Middleware** has hooks which are called for each HTTP request that
+/// the client sends to the Smartsheet API.
+///
+/// The `before_request` hooks are called in the order that the middleware
+/// was added to the client, and the `after_response` hooks in the reverse
+/// order. If a request is retried - for example, with a refreshed access
+/// token - the hooks are called again for each attempt.
+///
+/// # Example
+///
+/// ```
+/// use smartsheet_rs::middleware::Middleware;
+/// use async_trait::async_trait;
+/// use hyper::{Body, Request, Response};
+///
+/// struct LogStatus;
+///
+/// #[async_trait]
+/// impl Middleware for LogStatus {
+///     async fn after_response(
+///         &self,
+///         res: &Response<Body>,
+///     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+///         println!("Status: {}", res.status());
+///         Ok(())
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait Middleware: Send + Sync {
+    /// Called before a request is sent, once its headers - including the
+    /// `Authorization` header - have been set.
+    ///
+    /// The request can be modified as needed. Return `Some(response)` to
+    /// skip sending the request, and use `response` instead - for example,
+    /// a cached response. The default implementation returns `None`.
+    async fn before_request(&self, req: &mut Request<Body>) -> Result<Option<Response<Body>>> {
+        let _ = req;
+        Ok(None)
+    }
+
+    /// Called when a response is received, before its status is checked.
+    ///
+    /// Returning an error fails the request with that error. The default
+    /// implementation does nothing.
+    async fn after_response(&self, res: &Response<Body>) -> Result<()> {
+        let _ = res;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<T: Middleware + ?Sized> Middleware for Arc<T> {
+    async fn before_request(&self, req: &mut Request<Body>) -> Result<Option<Response<Body>>> {
+        (**self).before_request(req).await
+    }
+
+    async fn after_response(&self, res: &Response<Body>) -> Result<()> {
+        (**self).after_response(res).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::MockServer;
+    use crate::SmartsheetApi;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use hyper::header::HeaderValue;
+
+    #[derive(Default)]
+    struct CountResponses(AtomicUsize);
+
+    #[async_trait]
+    impl Middleware for CountResponses {
+        async fn before_request(&self, req: &mut Request<Body>) -> Result<Option<Response<Body>>> {
+            req.headers_mut()
+                .insert("x-request-source", HeaderValue::from_static("tests"));
+            Ok(None)
+        }
+
+        async fn after_response(&self, _res: &Response<Body>) -> Result<()> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    struct Cached;
+
+    #[async_trait]
+    impl Middleware for Cached {
+        async fn before_request(&self, _req: &mut Request<Body>) -> Result<Option<Response<Body>>> {
+            let body = r#"{"pageNumber": 1, "totalCount": 7, "totalPages": 1, "data": []}"#;
+            Ok(Some(Response::new(Body::from(body))))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_before_request_and_after_response() {
+        let server = MockServer::start(vec![(
+            200,
+            r#"{"pageNumber": 1, "totalCount": 0, "totalPages": 1, "data": []}"#,
+        )]);
+
+        let counter = Arc::new(CountResponses::default());
+
+        let endpoint = server.url("");
+        let smart = SmartsheetApi::from_endpoint_and_token(&endpoint, "my-token")
+            .with_middleware(counter.clone());
+
+        smart.list_sheets().await.unwrap();
+
+        assert!(server.requests()[0].contains("x-request-source: tests\r\n"));
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_before_request_short_circuits() {
+        let server = MockServer::start(vec![]);
+
+        let counter = Arc::new(CountResponses::default());
+
+        let endpoint = server.url("");
+        let smart = SmartsheetApi::from_endpoint_and_token(&endpoint, "my-token")
+            .with_middleware(counter.clone())
+            .with_middleware(Cached);
+
+        let sheets = smart.list_sheets().await.unwrap();
+
+        assert_eq!(sheets.total_count, 7);
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+        assert!(server.requests().is_empty());
+    }
+}
@@ -0,0 +1,94 @@
+use crate::models::Recipient;
+
+use core::option::Option;
+
+use serde::{Deserialize, Serialize};
+
+/// Represents the action of an `AutomationRule`, such as sending a
+/// notification or an update request.
+///
+/// # Docs
+/// <https://smartsheet.redoc.ly/#section/AutomationAction-Object>
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutomationAction {
+    /// The type of action.
+    #[serde(rename = "type")]
+    pub type_field: AutomationActionType,
+    /// Must be one of the predefined frequencies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<AutomationActionFrequency>,
+    /// Specifies whether to include all columns in email contents.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_all_columns: Option<bool>,
+    /// Specifies whether to include attachments in email contents.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_attachments: Option<bool>,
+    /// Specifies whether to include discussions in email contents.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_discussions: Option<bool>,
+    /// Specifies which columns to include in message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub included_column_ids: Option<Vec<u64>>,
+    /// Message body of the email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// If set to true, notification recipients are all the users who the
+    /// sheet is shared with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_all_shared_users: Option<bool>,
+    /// Array of recipients to notify.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipients: Option<Vec<Recipient>>,
+    /// Subject of the email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+}
+
+impl AutomationAction {
+    /// Create a new `AutomationAction` of a specified *type*
+    pub fn new(type_field: AutomationActionType) -> Self {
+        Self {
+            type_field,
+            frequency: None,
+            include_all_columns: None,
+            include_attachments: None,
+            include_discussions: None,
+            included_column_ids: None,
+            message: None,
+            notify_all_shared_users: None,
+            recipients: None,
+            subject: None,
+        }
+    }
+}
+
+/// Represents the type of an `AutomationAction`.
+///
+/// There is no default type, as the type of an existing rule can't be
+/// changed, and so it must always be specified when updating a rule.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AutomationActionType {
+    ApprovalRequestAction,
+    NotificationAction,
+    UpdateRequestAction,
+    /// An action type that this library doesn't know about yet. A rule with
+    /// this type can be read, but not updated.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Represents how often an `AutomationAction` is triggered.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AutomationActionFrequency {
+    Immediately,
+    Hourly,
+    Daily,
+    Weekly,
+    /// Any frequency not recognized by this library.
+    #[serde(other)]
+    Unknown,
+}
@@ -0,0 +1,169 @@
+use crate::models::{AutomationAction, AutomationActionType, User};
+use crate::utils::is_default;
+
+use core::option::Option;
+
+use serde::{Deserialize, Serialize};
+
+/// Represents an [Automation Rule], which determines the action to take,
+/// and the recipients to notify, when a sheet is changed.
+///
+/// # Note
+/// When updating a rule, the `action.type` must always be specified, and
+/// must match the type of the existing rule.
+///
+/// [Automation Rule]: https://smartsheet.redoc.ly/#section/AutomationRule-Object
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutomationRule {
+    /// Automation Rule Id
+    #[serde(skip_serializing_if = "is_default")]
+    pub id: u64,
+    /// Rule name as shown in the UI.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Indicates whether the rule is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// The action to take when the rule is triggered.
+    pub action: AutomationAction,
+    /// A timestamp of when the rule was originally added.
+    #[serde(skip_serializing)]
+    pub created_at: Option<String>,
+    /// User object containing name and email of the creator of this rule.
+    #[serde(skip_serializing)]
+    pub created_by: Option<User>,
+    /// Machine-readable reason a rule is disabled, such as
+    /// `APPROVAL_COLUMN_MISSING` or `NO_POTENTIAL_RECIPIENTS`.
+    #[serde(skip_serializing)]
+    pub disabled_reason: Option<String>,
+    /// Descriptive reason a rule is disabled.
+    #[serde(skip_serializing)]
+    pub disabled_reason_text: Option<String>,
+    /// A timestamp of when the rule was last modified.
+    #[serde(skip_serializing)]
+    pub modified_at: Option<String>,
+    /// User object containing name and email of the last person to modify
+    /// this rule.
+    #[serde(skip_serializing)]
+    pub modified_by: Option<User>,
+    /// Indicates whether the user can modify the rule.
+    #[serde(skip_serializing)]
+    pub user_can_modify: Option<bool>,
+}
+
+impl AutomationRule {
+    /// Create a new `AutomationRule` - for example, to update a rule - with
+    /// the *type* of its action, which must match the type of the existing
+    /// rule.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use smartsheet_rs::models::{AutomationActionType, AutomationRule};
+    ///
+    /// let disable = AutomationRule::new(AutomationActionType::UpdateRequestAction).enabled(false);
+    /// ```
+    pub fn new(action_type: AutomationActionType) -> Self {
+        Self {
+            id: 0,
+            name: None,
+            enabled: None,
+            action: AutomationAction::new(action_type),
+            created_at: None,
+            created_by: None,
+            disabled_reason: None,
+            disabled_reason_text: None,
+            modified_at: None,
+            modified_by: None,
+            user_can_modify: None,
+        }
+    }
+
+    /// Fluent setter for the `name` attribute
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Fluent setter for the `enabled` attribute
+    pub fn enabled<B: Into<Option<bool>>>(mut self, enabled: B) -> Self {
+        self.enabled = enabled.into();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::AutomationActionFrequency;
+    use serde_json::{from_str, to_string};
+
+    #[test]
+    fn test_serialize_update() {
+        for (action_type, name) in [
+            (
+                AutomationActionType::ApprovalRequestAction,
+                "APPROVAL_REQUEST_ACTION",
+            ),
+            (
+                AutomationActionType::NotificationAction,
+                "NOTIFICATION_ACTION",
+            ),
+            (
+                AutomationActionType::UpdateRequestAction,
+                "UPDATE_REQUEST_ACTION",
+            ),
+        ] {
+            let rule = AutomationRule::new(action_type).enabled(false);
+
+            assert_eq!(
+                to_string(&rule).unwrap(),
+                format!(r#"{{"enabled":false,"action":{{"type":"{}"}}}}"#, name)
+            );
+        }
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let rule: AutomationRule = from_str(
+            r#"{
+                "id": 123,
+                "name": "Request updates",
+                "enabled": true,
+                "action": {"type": "UPDATE_REQUEST_ACTION", "frequency": "IMMEDIATELY"},
+                "userCanModify": true
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(rule.id, 123);
+        assert_eq!(
+            rule.action.type_field,
+            AutomationActionType::UpdateRequestAction
+        );
+        assert_eq!(
+            rule.action.frequency,
+            Some(AutomationActionFrequency::Immediately)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_unknown_action() {
+        let rule: AutomationRule = from_str(
+            r#"{
+                "id": 456,
+                "enabled": true,
+                "action": {"type": "MOVE_ROW_ACTION", "frequency": "MONTHLY"}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(rule.action.type_field, AutomationActionType::Unknown);
+        assert_eq!(
+            rule.action.frequency,
+            Some(AutomationActionFrequency::Unknown)
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod automation_action;
+mod automation_rule;
+
+pub use self::automation_action::*;
+pub use self::automation_rule::*;
@@ -123,7 +123,7 @@ impl Cell {
         if let Some(value) = &self.value {
             value.as_str()
         } else {
-            Err(Box::new(Error::default()))
+            Err(Box::new(Error))
         }
     }
 
@@ -142,7 +142,7 @@ impl Cell {
         if let Some(value) = &self.value {
             value.as_number()
         } else {
-            Err(Box::new(Error::default()))
+            Err(Box::new(Error))
         }
     }
 
@@ -151,7 +151,7 @@ impl Cell {
         if let Some(value) = &self.value {
             value.as_bool()
         } else {
-            Err(Box::new(Error::default()))
+            Err(Box::new(Error))
         }
     }
 
@@ -160,7 +160,7 @@ impl Cell {
         if let Some(value) = &self.value {
             value.as_u64()
         } else {
-            Err(Box::new(Error::default()))
+            Err(Box::new(Error))
         }
     }
 
@@ -169,7 +169,7 @@ impl Cell {
         if let Some(value) = &self.value {
             value.as_f64()
         } else {
-            Err(Box::new(Error::default()))
+            Err(Box::new(Error))
         }
     }
 
@@ -178,7 +178,7 @@ impl Cell {
         if let Some(value) = &self.display_value {
             Ok(value)
         } else {
-            Err(Box::new(Error::default()))
+            Err(Box::new(Error))
         }
     }
 
@@ -196,7 +196,7 @@ impl Cell {
         if let Some(link) = &self.hyperlink {
             Ok(&link.url)
         } else {
-            Err(Box::new(Error::default()))
+            Err(Box::new(Error))
         }
     }
 
@@ -225,7 +225,7 @@ impl Cell {
                 }
             }
         }
-        Err(Box::new(Error::default()))
+        Err(Box::new(Error))
     }
 
     /// Retrieve info on a cell for a **MULTI_CONTACT** column. This returns a list
@@ -242,11 +242,11 @@ impl Cell {
             if let Some(values) = obj_value.get("values") {
                 return match from_value(values.to_owned()) {
                     Ok(v) => Ok(v),
-                    Err(_) => Err(Box::new(Error::default())),
+                    Err(_) => Err(Box::new(Error)),
                 };
             }
         }
-        Err(Box::new(Error::default()))
+        Err(Box::new(Error))
     }
 }
 
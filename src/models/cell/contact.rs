@@ -77,7 +77,7 @@ impl ContactEmailAddrs for Vec<ContactOwned> {
             .map(|contact| match &contact.name {
                 Some(name) => {
                     let mut s = String::with_capacity(name.len() + 3 + contact.email.len());
-                    s.push_str(&name);
+                    s.push_str(name);
                     s.push(' ');
                     s.push('<');
                     s.push_str(&contact.email);
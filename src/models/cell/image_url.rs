@@ -0,0 +1,141 @@
+use crate::models::{Image, SmartsheetError};
+
+use core::option::Option;
+
+use serde::{Deserialize, Serialize};
+
+/// Image URL object, used to request a temporary URL for a cell `Image`,
+/// and which is returned with the resolved `url` (or `error`) populated.
+///
+/// # Docs
+/// <https://smartsheet.redoc.ly/#section/ImageUrl-Object>
+///
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageUrl {
+    /// Image Id.
+    pub image_id: String,
+    /// Optional width (in pixels) of the image.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u64>,
+    /// Optional height (in pixels) of the image.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u64>,
+    /// Temporary URL that can be used to retrieve the image. This is not
+    /// populated if there was an error retrieving the image.
+    #[serde(skip_serializing)]
+    pub url: Option<String>,
+    /// Error retrieving the image, if any.
+    #[serde(skip_serializing)]
+    pub error: Option<SmartsheetError>,
+}
+
+impl ImageUrl {
+    /// Create a new `ImageUrl` for an *image id*, at the original size of
+    /// the image.
+    pub fn new<S: Into<String>>(image_id: S) -> Self {
+        Self {
+            image_id: image_id.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Fluent setter for the `width` attribute
+    pub fn width<U: Into<Option<u64>>>(mut self, width: U) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Fluent setter for the `height` attribute
+    pub fn height<U: Into<Option<u64>>>(mut self, height: U) -> Self {
+        self.height = height.into();
+        self
+    }
+}
+
+impl From<&Image> for ImageUrl {
+    fn from(image: &Image) -> Self {
+        Self::new(&image.id)
+    }
+}
+
+impl From<ImageUrl> for Vec<ImageUrl> {
+    fn from(image_url: ImageUrl) -> Self {
+        vec![image_url]
+    }
+}
+
+/// Image URL Map object, which is returned when requesting temporary URLs
+/// for cell images.
+///
+/// # Docs
+/// <https://smartsheet.redoc.ly/#section/ImageUrlMap-Object>
+///
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageUrlMap {
+    /// Array of `ImageUrl` objects.
+    #[serde(default)]
+    pub image_urls: Vec<ImageUrl>,
+    /// Milliseconds before the URLs expire.
+    pub url_expires_in_millis: u64,
+}
+
+impl ImageUrlMap {
+    /// Retrieve the temporary URL for a given *image id*, if it was
+    /// resolved successfully.
+    pub fn get_url(&self, image_id: &str) -> Option<&str> {
+        self.image_urls
+            .iter()
+            .find(|i| i.image_id == image_id)
+            .and_then(|i| i.url.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use serde_json::{from_str, to_string};
+
+    #[test]
+    fn test_serialize() {
+        let image_urls = vec![ImageUrl::new("abc123").width(64), ImageUrl::new("def456")];
+
+        assert_eq!(
+            to_string(&image_urls).unwrap(),
+            r#"[{"imageId":"abc123","width":64},{"imageId":"def456"}]"#
+        );
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let json = indoc! {r#"
+            {
+              "urlExpiresInMillis": 1800000,
+              "imageUrls": [
+                {
+                  "imageId": "abc123",
+                  "url": "https://images.example.com/abc123"
+                },
+                {
+                  "imageId": "def456",
+                  "error": {
+                    "errorCode": 1024,
+                    "message": "An unexpected error has occurred."
+                  }
+                }
+              ]
+            }
+        "#};
+
+        let map: ImageUrlMap = from_str(json).unwrap();
+
+        assert_eq!(
+            map.get_url("abc123"),
+            Some("https://images.example.com/abc123")
+        );
+        assert_eq!(map.get_url("def456"), None);
+        assert_eq!(map.image_urls[1].error.as_ref().unwrap().error_code, 1024);
+    }
+}
@@ -1,8 +1,10 @@
+#[allow(clippy::module_inception)]
 mod cell;
 mod contact;
 mod decision;
 mod hyperlink;
 mod image;
+mod image_url;
 mod light_picker;
 mod object_type;
 mod value;
@@ -12,6 +14,7 @@ pub use self::contact::*;
 pub use self::decision::*;
 pub use self::hyperlink::*;
 pub use self::image::*;
+pub use self::image_url::*;
 pub use self::light_picker::*;
 pub use self::object_type::*;
 pub use self::value::*;
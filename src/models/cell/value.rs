@@ -82,7 +82,7 @@ impl CellValue {
         if let Text(value) = &self {
             Ok(value)
         } else {
-            Err(Box::new(Error::default()))
+            Err(Box::new(Error))
         }
     }
 
@@ -98,7 +98,7 @@ impl CellValue {
         if let Numeric(value) = &self {
             Ok(value)
         } else {
-            Err(Box::new(Error::default()))
+            Err(Box::new(Error))
         }
     }
 
@@ -106,7 +106,7 @@ impl CellValue {
         if let Some(value) = self.as_number()?.as_u64() {
             Ok(value)
         } else {
-            Err(Box::new(Error::default()))
+            Err(Box::new(Error))
         }
     }
 
@@ -114,7 +114,7 @@ impl CellValue {
         if let Some(value) = self.as_number()?.as_f64() {
             Ok(value)
         } else {
-            Err(Box::new(Error::default()))
+            Err(Box::new(Error))
         }
     }
 
@@ -122,7 +122,7 @@ impl CellValue {
         if let Boolean(v) = &self {
             Ok(*v)
         } else {
-            Err(Box::new(Error::default()))
+            Err(Box::new(Error))
         }
     }
 }
@@ -44,7 +44,7 @@ impl RequestError {
 /// - https://smartsheet-platform.github.io/api-docs/#error-object
 /// - https://smartsheet-platform.github.io/api-docs/#complete-error-code-list
 ///
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SmartsheetError {
     pub message: String,
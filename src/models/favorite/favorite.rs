@@ -0,0 +1,89 @@
+use crate::models::EnumStr;
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Represents a [Favorite] - that is, an item which is starred by the user.
+///
+/// [Favorite]: https://smartsheet.redoc.ly/#section/Favorite-Object
+///
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Favorite {
+    /// Id of the favorited item.
+    pub object_id: u64,
+    /// Type of the favorited item.
+    #[serde(rename = "type")]
+    pub type_field: FavoriteType,
+}
+
+impl Favorite {
+    /// Create a new `Favorite` with a *type* and an *object id*.
+    pub fn new(type_field: FavoriteType, object_id: u64) -> Self {
+        Self {
+            object_id,
+            type_field,
+        }
+    }
+
+    /// Create a new `Favorite` for a sheet, with a *sheet id*.
+    pub fn sheet(sheet_id: u64) -> Self {
+        Self::new(FavoriteType::Sheet, sheet_id)
+    }
+}
+
+impl From<Favorite> for Vec<Favorite> {
+    /// Useful when adding a favorite.
+    fn from(favorite: Favorite) -> Self {
+        vec![favorite]
+    }
+}
+
+/// The type of item which is favorited.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FavoriteType {
+    Folder,
+    Report,
+    #[default]
+    Sheet,
+    Sight,
+    Template,
+    Workspace,
+}
+
+impl EnumStr for FavoriteType {
+    fn as_str<'a>(&self) -> &'a str {
+        match self {
+            Self::Folder => "folder",
+            Self::Report => "report",
+            Self::Sheet => "sheet",
+            Self::Sight => "sight",
+            Self::Template => "template",
+            Self::Workspace => "workspace",
+        }
+    }
+}
+
+impl fmt::Display for FavoriteType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::to_string;
+
+    #[test]
+    fn test_serialize() {
+        let favorites: Vec<Favorite> = Favorite::sheet(123).into();
+
+        assert_eq!(
+            to_string(&favorites).unwrap(),
+            r#"[{"objectId":123,"type":"sheet"}]"#
+        );
+    }
+}
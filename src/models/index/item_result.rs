@@ -0,0 +1,23 @@
+use crate::models::Message;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// **Item Result** - Object returned for POST and PUT operations which
+/// create or update a *single* object, such as a cross-sheet reference.
+/// Represents a [Result] object.
+///
+/// [Result]: https://smartsheet.redoc.ly/#section/Result-Object
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemResult<T> {
+    pub message: Message,
+    pub result: T,
+    pub result_code: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<i64>,
+}
+
+/// **Empty Result** - Object returned for operations which don't return
+/// any data, such as a DELETE operation for a single object.
+pub type EmptyResult = ItemResult<Option<Value>>;
@@ -1,3 +1,5 @@
 mod index_result;
+mod item_result;
 
 pub use self::index_result::*;
+pub use self::item_result::*;
@@ -1,17 +1,31 @@
 //! Library-specific models for interacting with the Smartsheet API.
 //!
+mod automation;
 mod cell;
 mod column;
 mod error;
+mod favorite;
 mod index;
 mod params;
+mod proof;
 mod row;
+mod server;
 mod sheet;
+mod summary;
+mod template;
+mod update_request;
 
+pub use self::automation::*;
 pub use self::cell::*;
 pub use self::column::*;
 pub use self::error::*;
+pub use self::favorite::*;
 pub use self::index::*;
 pub use self::params::*;
+pub use self::proof::*;
 pub use self::row::*;
+pub use self::server::*;
 pub use self::sheet::*;
+pub use self::summary::*;
+pub use self::template::*;
+pub use self::update_request::*;
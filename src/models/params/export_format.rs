@@ -0,0 +1,85 @@
+use crate::models::EnumStr;
+
+use core::fmt;
+
+/// The format to export a sheet or report in, which is passed along in the
+/// `Accept` header of a **Get Sheet** or **Get Report** request.
+///
+/// # Docs
+/// - https://smartsheet-platform.github.io/api-docs/#get-sheet-as-excel-pdf-csv
+/// - https://smartsheet-platform.github.io/api-docs/#get-report-as-excel-csv
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ExportFormat {
+    /// `text/csv`
+    Csv,
+    /// `application/vnd.ms-excel`
+    Excel,
+    /// `application/pdf`, with the paper size to render the document on.
+    ///
+    /// # Note
+    /// This format is only supported when exporting a *sheet*.
+    Pdf(PaperSize),
+}
+
+impl ExportFormat {
+    /// Returns the MIME type to set in the `Accept` header for a request.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::Excel => "application/vnd.ms-excel",
+            Self::Pdf(_) => "application/pdf",
+        }
+    }
+
+    /// Returns the paper size for the export, which is only set for the
+    /// `Pdf` format.
+    pub fn paper_size(&self) -> Option<PaperSize> {
+        match self {
+            Self::Pdf(paper_size) => Some(*paper_size),
+            _ => None,
+        }
+    }
+}
+
+/// Paper sizes which are supported when exporting a sheet as PDF.
+///
+/// # Docs
+/// - https://smartsheet-platform.github.io/api-docs/#get-sheet-as-excel-pdf-csv
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum PaperSize {
+    /// LETTER (default)
+    #[default]
+    Letter,
+    Legal,
+    Wide,
+    ArchD,
+    A4,
+    A3,
+    A2,
+    A1,
+    A0,
+}
+
+impl EnumStr for PaperSize {
+    fn as_str<'a>(&self) -> &'a str {
+        match self {
+            Self::Letter => "LETTER",
+            Self::Legal => "LEGAL",
+            Self::Wide => "WIDE",
+            Self::ArchD => "ARCHD",
+            Self::A4 => "A4",
+            Self::A3 => "A3",
+            Self::A2 => "A2",
+            Self::A1 => "A1",
+            Self::A0 => "A0",
+        }
+    }
+}
+
+impl fmt::Display for PaperSize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
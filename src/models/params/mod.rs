@@ -1,14 +1,22 @@
+mod export_format;
 mod get_column;
 mod get_row;
 mod get_sheet;
 mod level;
 mod list_sheets;
+mod proofs;
+mod summary_fields;
+mod templates;
 
+pub use self::export_format::*;
 pub use self::get_column::*;
 pub use self::get_row::*;
 pub use self::get_sheet::*;
 pub use self::level::*;
 pub use self::list_sheets::*;
+pub use self::proofs::*;
+pub use self::summary_fields::*;
+pub use self::templates::*;
 
 /// Allows enums to serialize themselves to a string value.
 pub(crate) trait EnumStr {
@@ -0,0 +1,28 @@
+use crate::models::EnumStr;
+
+use std::fmt;
+
+/// Proof Include Flags are documented here:
+///   https://smartsheet.redoc.ly/#operation/proofs-get
+#[derive(Debug)]
+pub enum ProofIncludeFlags {
+    /// Includes proof `attachments` array.
+    Attachments,
+    /// Includes proof `discussions` array.
+    Discussions,
+}
+
+impl EnumStr for ProofIncludeFlags {
+    fn as_str<'a>(&self) -> &'a str {
+        match self {
+            Self::Attachments => "attachments",
+            Self::Discussions => "discussions",
+        }
+    }
+}
+
+impl fmt::Display for ProofIncludeFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
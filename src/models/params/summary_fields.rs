@@ -0,0 +1,57 @@
+use crate::models::EnumStr;
+
+use std::fmt;
+
+/// Summary Field Include Flags are documented here:
+///   https://smartsheet.redoc.ly/#operation/list-summary-fields
+#[derive(Debug)]
+pub enum SummaryFieldIncludeFlags {
+    /// Includes `format` attribute on the summary fields.
+    Format,
+    /// Includes `createdBy` and `modifiedBy` attributes on the summary
+    /// fields, indicating the field's creator, and last modifier.
+    WriterInfo,
+}
+
+impl EnumStr for SummaryFieldIncludeFlags {
+    fn as_str<'a>(&self) -> &'a str {
+        match self {
+            Self::Format => "format",
+            Self::WriterInfo => "writerInfo",
+        }
+    }
+}
+
+impl fmt::Display for SummaryFieldIncludeFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Summary Field Exclude Flags are documented here:
+///   https://smartsheet.redoc.ly/#operation/list-summary-fields
+#[derive(Debug)]
+pub enum SummaryFieldExcludeFlags {
+    /// Excludes the `displayValue` attribute on the summary fields.
+    DisplayValue,
+    /// Excludes the `image` attribute on the summary fields.
+    Image,
+    /// Excludes the `altText` attribute of any image in the summary fields.
+    ImageAltText,
+}
+
+impl EnumStr for SummaryFieldExcludeFlags {
+    fn as_str<'a>(&self) -> &'a str {
+        match self {
+            Self::DisplayValue => "displayValue",
+            Self::Image => "image",
+            Self::ImageAltText => "imageAltText",
+        }
+    }
+}
+
+impl fmt::Display for SummaryFieldExcludeFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,40 @@
+use crate::models::EnumStr;
+
+use std::fmt;
+
+/// Sheet From Template Include Flags are documented here:
+///   https://smartsheet.redoc.ly/#operation/create-sheet-in-sheets-folder
+///
+/// These specify which elements to copy from the template.
+#[derive(Debug)]
+pub enum SheetFromTemplateIncludeFlags {
+    Attachments,
+    CellLinks,
+    Data,
+    Discussions,
+    Filters,
+    Forms,
+    RuleRecipients,
+    Rules,
+}
+
+impl EnumStr for SheetFromTemplateIncludeFlags {
+    fn as_str<'a>(&self) -> &'a str {
+        match self {
+            Self::Attachments => "attachments",
+            Self::CellLinks => "cellLinks",
+            Self::Data => "data",
+            Self::Discussions => "discussions",
+            Self::Filters => "filters",
+            Self::Forms => "forms",
+            Self::RuleRecipients => "ruleRecipients",
+            Self::Rules => "rules",
+        }
+    }
+}
+
+impl fmt::Display for SheetFromTemplateIncludeFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod proof;
+mod proof_request;
+
+pub use self::proof::*;
+pub use self::proof_request::*;
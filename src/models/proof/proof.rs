@@ -0,0 +1,41 @@
+use crate::models::{AttachmentMeta, Discussion, User};
+
+use core::option::Option;
+
+use serde::{Deserialize, Serialize};
+
+/// Represents a [Proof], which is a file (such as an image or document)
+/// attached to a row for review and approval.
+///
+/// [Proof]: https://smartsheet.redoc.ly/#section/Proof-Object
+///
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Proof {
+    /// Proof Id of the proof version.
+    pub id: u64,
+    /// Proof Id of the original proof (the first version).
+    pub original_id: Option<u64>,
+    /// Name of the proof.
+    pub name: Option<String>,
+    /// Type of proof, such as `IMAGE`, `DOCUMENT`, `VIDEO` or `MIXED`.
+    #[serde(rename = "type")]
+    pub type_field: Option<String>,
+    /// The version number of the proof.
+    pub version: Option<u64>,
+    /// Indicates whether the proof is completed.
+    pub is_completed: Option<bool>,
+    /// The date and time for when the proof was last updated.
+    pub last_updated_at: Option<String>,
+    /// User object containing name and email of the last person to update
+    /// this proof.
+    pub last_updated_by: Option<User>,
+    /// URL to review a proofing request.
+    pub proof_request_url: Option<String>,
+    /// Attachments on the proof. Only returned if the include query string
+    /// parameter contains attachments.
+    pub attachments: Option<Vec<AttachmentMeta>>,
+    /// Discussions on the proof. Only returned if the include query string
+    /// parameter contains discussions.
+    pub discussions: Option<Vec<Discussion>>,
+}
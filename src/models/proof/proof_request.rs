@@ -0,0 +1,82 @@
+use crate::models::{Recipient, User};
+
+use core::option::Option;
+
+use serde::{Deserialize, Serialize};
+
+/// Represents a request for reviewers to approve (or reject) a `Proof`.
+///
+/// # Docs
+/// <https://smartsheet.redoc.ly/#operation/proofs-createProofRequests>
+///
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofRequest {
+    /// Array of recipients to send the proof request to.
+    #[serde(default)]
+    pub send_to: Vec<Recipient>,
+    /// Subject of the email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// Message body of the email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Indicates whether the sender should receive a copy of the email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cc_me: Option<bool>,
+    /// Indicates whether the proof is downloadable by the reviewers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_downloadable: Option<bool>,
+}
+
+impl ProofRequest {
+    /// Create a new `ProofRequest` which is to be sent to a list of
+    /// *recipients*.
+    pub fn new<R: Into<Recipient>>(send_to: impl IntoIterator<Item = R>) -> Self {
+        Self {
+            send_to: send_to.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Fluent setter for the `subject` attribute
+    pub fn subject<S: Into<String>>(mut self, subject: S) -> Self {
+        self.subject = Some(subject.into());
+        self
+    }
+
+    /// Fluent setter for the `message` attribute
+    pub fn message<S: Into<String>>(mut self, message: S) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Fluent setter for the `cc_me` attribute
+    pub fn cc_me<B: Into<Option<bool>>>(mut self, cc_me: B) -> Self {
+        self.cc_me = cc_me.into();
+        self
+    }
+
+    /// Fluent setter for the `is_downloadable` attribute
+    pub fn is_downloadable<B: Into<Option<bool>>>(mut self, is_downloadable: B) -> Self {
+        self.is_downloadable = is_downloadable.into();
+        self
+    }
+}
+
+/// Represents an action - such as an approval or a rejection - taken by a
+/// reviewer on a proof request.
+///
+/// # Docs
+/// <https://smartsheet.redoc.ly/#operation/proofs-getRequestActions>
+///
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofRequestAction {
+    /// The action taken by the reviewer, such as `APPROVED` or `REJECTED`.
+    pub action: Option<String>,
+    /// The date and time for when the action was taken.
+    pub actioned_at: Option<String>,
+    /// User object containing name and email of the reviewer.
+    pub user: Option<User>,
+}
@@ -22,10 +22,16 @@ pub struct AttachmentMeta {
     /// # Note
     /// `Smartsheetgov.com` accounts are restricted to the following
     /// attachment types: BOX_COM, FILE, GOOGLE_DRIVE, LINK, or ONEDRIVE.
+    /// This is checked when attaching a URL with a `Region::Gov` client.
     pub attachment_type: AttachmentType,
     /// A timestamp of when the attachment was originally added
+    #[serde(default)]
     pub created_at: String,
     /// User object containing name and email of the creator of this attachment
+    ///
+    /// # Note
+    /// This field is not populated in the response when an attachment is added
+    #[serde(default)]
     pub created_by: User,
     /// The Id of the parent
     ///
@@ -3,9 +3,11 @@ mod attachment_type;
 mod meta;
 mod model;
 mod parent_type;
+mod url_attachment;
 
 pub use attachment_sub_type::*;
 pub use attachment_type::*;
 pub use meta::*;
 pub use model::*;
 pub use parent_type::*;
+pub use url_attachment::*;
@@ -5,16 +5,11 @@ use serde::{Deserialize, Serialize};
 /// # Docs
 /// <https://smartsheet-platform.github.io/api-docs/#objects>
 ///
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ParentType {
     Comment,
+    #[default]
     Row,
     Sheet,
 }
-
-impl Default for ParentType {
-    fn default() -> Self {
-        Self::Row
-    }
-}
@@ -0,0 +1,56 @@
+use super::*;
+
+use serde::{Deserialize, Serialize};
+
+/// Request body used to attach a URL - such as a link, or a file stored in
+/// a cloud service - to a sheet or a row.
+///
+/// # Docs
+/// <https://smartsheet.redoc.ly/#operation/attachments-attachToSheet>
+///
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UrlAttachment {
+    /// Attachment type (one of `AttachmentType`, other than `FILE`)
+    pub attachment_type: AttachmentType,
+    /// Attachment sub type, valid only for the following attachment
+    /// types: EGNYTE, GOOGLE_DRIVE
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachment_sub_type: Option<AttachmentSubType>,
+    /// Attachment name
+    pub name: String,
+    /// Attachment URL
+    pub url: String,
+    /// Attachment description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl UrlAttachment {
+    /// Create a new `UrlAttachment` with a *type*, *name* and *URL*.
+    pub fn new<S: Into<String>>(attachment_type: AttachmentType, name: S, url: S) -> Self {
+        Self {
+            attachment_type,
+            name: name.into(),
+            url: url.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Create a new `UrlAttachment` for a link, with a *name* and *URL*.
+    pub fn link<S: Into<String>>(name: S, url: S) -> Self {
+        Self::new(AttachmentType::Link, name, url)
+    }
+
+    /// Fluent setter for the `attachment_sub_type` attribute
+    pub fn sub_type<T: Into<Option<AttachmentSubType>>>(mut self, sub_type: T) -> Self {
+        self.attachment_sub_type = sub_type.into();
+        self
+    }
+
+    /// Fluent setter for the `description` attribute
+    pub fn description<S: Into<String>>(mut self, description: S) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
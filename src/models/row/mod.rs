@@ -2,6 +2,7 @@ mod access_level;
 mod attachment;
 mod discussion;
 mod indent;
+#[allow(clippy::module_inception)]
 mod row;
 mod row_result;
 mod user;
@@ -1,4 +1,6 @@
-use crate::models::{AccessLevel, AttachmentMeta, Cell, Column, Discussion, IndentEnabled, User};
+use crate::models::{
+    AccessLevel, AttachmentMeta, Cell, Column, Discussion, IndentEnabled, Proof, User,
+};
 use crate::types::Result;
 use crate::utils::is_default;
 
@@ -83,9 +85,10 @@ pub struct Row {
     /// Sheet version number that is incremented every time a sheet is modified.
     #[serde(skip_serializing)]
     pub version: Option<u64>,
-    // TODO: Add Proof field
-    // Proof Object
-    // pub proofs: Proofs
+    /// Proof on the row. Only returned if the include query string parameter
+    /// contains `proofs`, when retrieving a sheet.
+    #[serde(skip_serializing)]
+    pub proofs: Option<Proof>,
     /// # Note
     ///
     /// The following are used in the [Row Location] specified attributes.
@@ -167,13 +170,13 @@ impl Row {
 
     /// Retrieve a specified `Cell` - for a given *column id* - from the `Row`
     pub fn get_cell_by_id(&self, column_id: u64) -> Result<&Cell> {
-        return match self.cells.iter().find(|cell| cell.column_id == column_id) {
+        match self.cells.iter().find(|cell| cell.column_id == column_id) {
             Some(cell) => Ok(cell),
             None => Err(Box::from(Error::new(
                 ErrorKind::NotFound,
                 "No cell found for the given Column ID or Name",
             ))),
-        };
+        }
     }
 
     /// Fluent setter for the `id` attribute (Row Id)
@@ -380,6 +383,7 @@ mod tests {
             permalink: Some("test link".to_owned()),
             row_number: 123,
             version: Some(111),
+            proofs: None,
             sibling_id: Some(123),
             parent_id: Some(321),
             to_top: Some(true),
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents the [Format Tables], which contain all the supported values
+/// for each element of a format descriptor.
+///
+/// [Format Tables]: https://smartsheet.redoc.ly/#section/API-Basics/Formatting
+///
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FormatTables {
+    /// The default format descriptor.
+    pub defaults: String,
+    pub background_color: Vec<String>,
+    pub bold: Vec<String>,
+    pub color: Vec<String>,
+    pub currency: Vec<Currency>,
+    pub date_format: Vec<String>,
+    pub decimal_count: Vec<String>,
+    pub font_family: Vec<FontFamily>,
+    pub font_size: Vec<String>,
+    pub horizontal_align: Vec<String>,
+    pub italic: Vec<String>,
+    pub number_format: Vec<String>,
+    pub strikethrough: Vec<String>,
+    pub text_color: Vec<String>,
+    pub text_wrap: Vec<String>,
+    pub thousands_separator: Vec<String>,
+    pub underline: Vec<String>,
+    pub vertical_align: Vec<String>,
+}
+
+/// Currency entry within the `FormatTables`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Currency {
+    /// The ISO 4217 currency code, for example `USD`.
+    pub code: String,
+    /// The currency symbol, for example `$`.
+    pub symbol: String,
+}
+
+/// Font family entry within the `FormatTables`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FontFamily {
+    /// Name of the font family, for example `Arial`.
+    pub name: String,
+    /// Platform-independent traits of the font family, for example `sans-serif`.
+    #[serde(default)]
+    pub traits: Vec<String>,
+}
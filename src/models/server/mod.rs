@@ -0,0 +1,5 @@
+mod format_tables;
+mod server_info;
+
+pub use self::format_tables::*;
+pub use self::server_info::*;
@@ -0,0 +1,58 @@
+use crate::models::FormatTables;
+
+use serde::{Deserialize, Serialize};
+
+/// Represents the [Server Info], which contains application constants
+/// such as the supported locales and time zones, and the format tables.
+///
+/// [Server Info]: https://smartsheet.redoc.ly/#section/ServerInfo-Object
+///
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerInfo {
+    /// Definition of format tables that are used in the `format`
+    /// attribute of cells, columns, rows, and summary fields.
+    pub formats: FormatTables,
+    /// Array of strings representing all Smartsheet-supported locales.
+    #[serde(default)]
+    pub supported_locales: Vec<String>,
+    /// Array of strings representing all Smartsheet-supported time zones,
+    /// such as `US/Pacific`.
+    #[serde(default)]
+    pub supported_time_zones: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use serde_json::from_str;
+
+    #[test]
+    fn test_deserialize() {
+        let json = indoc! {r#"
+            {
+              "supportedLocales": ["en_US", "de_DE"],
+              "supportedTimeZones": ["US/Pacific", "Europe/Berlin"],
+              "formats": {
+                "defaults": ",,,,,,,,,,,,,,,,",
+                "bold": ["none", "on"],
+                "currency": [{ "code": "USD", "symbol": "$" }],
+                "fontFamily": [{ "name": "Arial", "traits": ["sans-serif"] }]
+              }
+            }
+        "#};
+
+        let info: ServerInfo = from_str(json).unwrap();
+
+        assert_eq!(info.supported_locales, vec!["en_US", "de_DE"]);
+        assert_eq!(
+            info.supported_time_zones,
+            vec!["US/Pacific", "Europe/Berlin"]
+        );
+        assert_eq!(info.formats.bold, vec!["none", "on"]);
+        assert_eq!(info.formats.currency[0].symbol, "$");
+        assert_eq!(info.formats.font_family[0].traits, vec!["sans-serif"]);
+        assert!(info.formats.italic.is_empty());
+    }
+}
@@ -1,3 +1,9 @@
+use crate::models::{Cell, CellValue, FilterOperator, Row};
+use crate::types::Result;
+
+use std::io::{Error, ErrorKind};
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -5,7 +11,401 @@ use serde_json::Value;
 #[serde(rename_all = "camelCase")]
 pub struct Criteria {
     pub column_id: u64,
-    pub operator: String,
+    pub operator: FilterOperator,
     #[serde(default)]
     pub values: Vec<Value>,
 }
+
+impl Criteria {
+    /// Evaluate the criteria against the cell in a `Row` for the `column_id`.
+    ///
+    /// Text comparisons are case-insensitive, as they are in the
+    /// Smartsheet UI. Date operators compare against the current date in
+    /// UTC.
+    ///
+    /// # Errors
+    /// Returns an error for operators that can't be evaluated locally, such
+    /// as `IS_CURRENT_USER`, or when the criteria `values` are not valid for
+    /// the operator.
+    pub fn matches(&self, row: &Row) -> Result<bool> {
+        let cell = row.get_cell_by_id(self.column_id).ok();
+        let text = cell.and_then(cell_text);
+
+        use FilterOperator::*;
+
+        let result = match self.operator {
+            IsBlank => text.is_none(),
+            IsNotBlank => text.is_some(),
+            IsNumber => matches!(cell_value(cell), Some(CellValue::Numeric(_))),
+            IsNotNumber => !matches!(cell_value(cell), Some(CellValue::Numeric(_))),
+            IsDate => text.as_deref().and_then(parse_date).is_some(),
+            IsNotDate => text.as_deref().and_then(parse_date).is_none(),
+            IsChecked => matches!(cell_value(cell), Some(CellValue::Boolean(true))),
+            IsNotChecked => !matches!(cell_value(cell), Some(CellValue::Boolean(true))),
+            Equal => self.text_eq(cell, self.value(0)?),
+            NotEqual => !self.text_eq(cell, self.value(0)?),
+            Contains => contains(&text, self.value(0)?),
+            DoesNotContain => !contains(&text, self.value(0)?),
+            GreaterThan => self.compare(cell, 0)?.is_some_and(|o| o.is_gt()),
+            LessThan => self.compare(cell, 0)?.is_some_and(|o| o.is_lt()),
+            GreaterThanOrEqual => self.compare(cell, 0)?.is_some_and(|o| o.is_ge()),
+            LessThanOrEqual => self.compare(cell, 0)?.is_some_and(|o| o.is_le()),
+            Between => self.between(cell)?,
+            NotBetween => !self.between(cell)?,
+            IsOneOf => self.values.iter().any(|v| self.text_eq(cell, v)),
+            IsNotOneOf => !self.values.iter().any(|v| self.text_eq(cell, v)),
+            HasAnyOf => self.count_multi(cell) > 0,
+            HasNoneOf => self.count_multi(cell) == 0,
+            HasAllOf => self.count_multi(cell) == self.values.len(),
+            NotAllOf => self.count_multi(cell) != self.values.len(),
+            MultiIsEqual => self.multi_eq(cell),
+            MultiIsNotEqual => !self.multi_eq(cell),
+            Today => days_from_today(&text) == Some(0),
+            NotToday => days_from_today(&text) != Some(0),
+            Past => days_from_today(&text).is_some_and(|d| d < 0),
+            NotPast => !matches!(days_from_today(&text), Some(d) if d < 0),
+            Future => days_from_today(&text).is_some_and(|d| d > 0),
+            NotFuture => !matches!(days_from_today(&text), Some(d) if d > 0),
+            LastNDays => self.within_days(&text, false)?,
+            NotLastNDays => !self.within_days(&text, false)?,
+            NextNDays => self.within_days(&text, true)?,
+            NotNextNDays => !self.within_days(&text, true)?,
+            IsCurrentUser | IsNotCurrentUser | Unknown(_) => {
+                return Err(Box::from(Error::new(
+                    ErrorKind::Unsupported,
+                    format!(
+                        "The filter operator `{:?}` can't be evaluated locally",
+                        self.operator
+                    ),
+                )));
+            }
+        };
+
+        Ok(result)
+    }
+
+    /// Retrieve the criteria value at the specified `index`.
+    fn value(&self, index: usize) -> Result<&Value> {
+        match self.values.get(index) {
+            Some(v) => Ok(v),
+            None => Err(Box::from(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "The filter operator `{:?}` requires at least {} value(s)",
+                    self.operator,
+                    index + 1
+                ),
+            ))),
+        }
+    }
+
+    /// Compare a cell against a criteria value for equality.
+    fn text_eq(&self, cell: Option<&Cell>, value: &Value) -> bool {
+        match (cell.and_then(cell_text), value_text(value)) {
+            (Some(a), Some(b)) => match (a.parse::<f64>(), b.parse::<f64>()) {
+                (Ok(x), Ok(y)) => x == y,
+                _ => a.to_lowercase() == b.to_lowercase(),
+            },
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    /// Compare a cell against the criteria value at `index`, either as a
+    /// number, as a date, or otherwise as text.
+    fn compare(&self, cell: Option<&Cell>, index: usize) -> Result<Option<std::cmp::Ordering>> {
+        let other = value_text(self.value(index)?);
+        let text = cell.and_then(cell_text);
+
+        Ok(match (text, other) {
+            (Some(a), Some(b)) => match (a.parse::<f64>(), b.parse::<f64>()) {
+                (Ok(x), Ok(y)) => x.partial_cmp(&y),
+                _ => match (parse_date(&a), parse_date(&b)) {
+                    (Some(x), Some(y)) => Some(x.cmp(&y)),
+                    _ => Some(a.to_lowercase().cmp(&b.to_lowercase())),
+                },
+            },
+            _ => None,
+        })
+    }
+
+    /// Determine if a cell is between the first two criteria values,
+    /// inclusive.
+    fn between(&self, cell: Option<&Cell>) -> Result<bool> {
+        let lower = self.compare(cell, 0)?;
+        let upper = self.compare(cell, 1)?;
+
+        Ok(lower.is_some_and(|o| o.is_ge()) && upper.is_some_and(|o| o.is_le()))
+    }
+
+    /// Count how many of the criteria values are contained in a
+    /// multi-value (such as a `MULTI_PICKLIST` or `MULTI_CONTACT`) cell.
+    fn count_multi(&self, cell: Option<&Cell>) -> usize {
+        let cell_values = multi_values(cell);
+
+        self.values
+            .iter()
+            .filter_map(value_text)
+            .filter(|v| cell_values.contains(&v.to_lowercase()))
+            .count()
+    }
+
+    /// Determine if a multi-value cell contains exactly the criteria values.
+    fn multi_eq(&self, cell: Option<&Cell>) -> bool {
+        let cell_values = multi_values(cell);
+
+        cell_values.len() == self.values.len() && self.count_multi(cell) == self.values.len()
+    }
+
+    /// Determine if a date cell is within the last (or next) *N* days, where
+    /// *N* is the first criteria value.
+    fn within_days(&self, text: &Option<String>, next: bool) -> Result<bool> {
+        let n = match value_text(self.value(0)?).and_then(|v| v.parse::<i64>().ok()) {
+            Some(n) => n,
+            None => {
+                return Err(Box::from(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The number of days must be an integer",
+                )));
+            }
+        };
+
+        Ok(match days_from_today(text) {
+            Some(d) if next => (0..=n).contains(&d),
+            Some(d) => (-n..=0).contains(&d),
+            None => false,
+        })
+    }
+}
+
+/// Retrieve the `value` of a cell, if it is set.
+fn cell_value(cell: Option<&Cell>) -> Option<&CellValue> {
+    cell.and_then(|c| c.value.as_ref())
+}
+
+/// Retrieve the value of a cell as text, falling back to the display
+/// value. Empty values are treated as blank.
+fn cell_text(cell: &Cell) -> Option<String> {
+    let text = match &cell.value {
+        Some(CellValue::Text(s)) => s.to_owned(),
+        Some(CellValue::Numeric(n)) => n.to_string(),
+        Some(CellValue::Boolean(b)) => b.to_string(),
+        None => cell.display_value.to_owned()?,
+    };
+
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+/// Retrieve a criteria value as text. Object values - such as a contact or
+/// a date - are resolved to their `email` or `value` field.
+fn value_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.to_owned()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Object(o) => ["email", "value", "name"]
+            .iter()
+            .find_map(|k| o.get(*k))
+            .and_then(value_text),
+        _ => None,
+    }
+}
+
+/// Retrieve the (lower-cased) values of a multi-value cell, either from
+/// its `object_value`, or otherwise by splitting its text value.
+fn multi_values(cell: Option<&Cell>) -> Vec<String> {
+    let cell = match cell {
+        Some(c) => c,
+        None => return vec![],
+    };
+
+    match cell.values() {
+        Ok(values) => values
+            .iter()
+            .filter_map(value_text)
+            .map(|v| v.to_lowercase())
+            .collect(),
+        Err(_) => cell_text(cell)
+            .map(|t| {
+                t.split(',')
+                    .map(|v| v.trim().to_lowercase())
+                    .filter(|v| !v.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default(),
+    }
+}
+
+/// Determine if `text` contains a criteria value, ignoring case.
+fn contains(text: &Option<String>, value: &Value) -> bool {
+    match (text, value_text(value)) {
+        (Some(t), Some(v)) => t.to_lowercase().contains(&v.to_lowercase()),
+        _ => false,
+    }
+}
+
+/// Parse a date - or the date part of an ISO-8601 datetime - into the
+/// number of days since the Unix epoch.
+fn parse_date(text: &str) -> Option<i64> {
+    let date = text.get(..10)?;
+    let mut parts = date.splitn(3, '-');
+
+    let y: i64 = parts.next()?.parse().ok()?;
+    let m: i64 = parts.next()?.parse().ok()?;
+    let d: i64 = parts.next()?.parse().ok()?;
+
+    if date.len() != 10 || !(1..=12).contains(&m) || !(1..=31).contains(&d) {
+        return None;
+    }
+
+    Some(days_from_civil(y, m, d))
+}
+
+/// Returns the number of days from today (UTC) to the date in `text`; this
+/// is negative for a date in the past.
+fn days_from_today(text: &Option<String>) -> Option<i64> {
+    let date = parse_date(text.as_deref()?)?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?;
+
+    Some(date - (now.as_secs() / 86_400) as i64)
+}
+
+/// Convert a date in the proleptic Gregorian calendar into the number of
+/// days since the Unix epoch.
+///
+/// # Credits
+/// http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn row(value: Value) -> Row {
+        let cell: Cell = serde_json::from_value(json!({ "columnId": 1, "value": value })).unwrap();
+        Row::with_cells(vec![cell])
+    }
+
+    fn criteria(operator: FilterOperator, values: Vec<Value>) -> Criteria {
+        Criteria {
+            column_id: 1,
+            operator,
+            values,
+        }
+    }
+
+    #[test]
+    fn test_deserialize_operator() {
+        let c: Criteria = serde_json::from_value(json!({
+            "columnId": 1,
+            "operator": "LAST_N_DAYS",
+            "values": [7]
+        }))
+        .unwrap();
+        assert_eq!(c.operator, FilterOperator::LastNDays);
+
+        let c: Criteria =
+            serde_json::from_value(json!({ "columnId": 1, "operator": "SOMETHING_NEW" })).unwrap();
+        assert_eq!(
+            c.operator,
+            FilterOperator::Unknown("SOMETHING_NEW".to_owned())
+        );
+
+        // The original operator is kept when serializing the criteria again
+        let value = serde_json::to_value(&c).unwrap();
+        assert_eq!(value["operator"], "SOMETHING_NEW");
+        assert_eq!(
+            serde_json::to_value(FilterOperator::NotLastNDays).unwrap(),
+            "NOT_LAST_N_DAYS"
+        );
+    }
+
+    #[test]
+    fn test_text_and_numeric_operators() {
+        let r = row(json!("Hello World"));
+        assert!(criteria(FilterOperator::Equal, vec![json!("hello world")])
+            .matches(&r)
+            .unwrap());
+        assert!(criteria(FilterOperator::Contains, vec![json!("WORLD")])
+            .matches(&r)
+            .unwrap());
+        assert!(criteria(FilterOperator::IsNotBlank, vec![])
+            .matches(&r)
+            .unwrap());
+
+        let r = row(json!(42));
+        assert!(criteria(FilterOperator::GreaterThan, vec![json!(9)])
+            .matches(&r)
+            .unwrap());
+        assert!(
+            criteria(FilterOperator::Between, vec![json!(40), json!("42")])
+                .matches(&r)
+                .unwrap()
+        );
+        assert!(criteria(FilterOperator::IsNumber, vec![])
+            .matches(&r)
+            .unwrap());
+        assert!(criteria(FilterOperator::IsOneOf, vec![json!(1), json!(42)])
+            .matches(&r)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_date_operators() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(parse_date("2000-03-01T12:00:00Z"), Some(11_017));
+        assert_eq!(parse_date("not a date"), None);
+
+        let r = row(json!("1999-12-31"));
+        assert!(criteria(FilterOperator::Past, vec![]).matches(&r).unwrap());
+        assert!(
+            criteria(FilterOperator::LessThan, vec![json!("2000-01-01")])
+                .matches(&r)
+                .unwrap()
+        );
+        assert!(!criteria(FilterOperator::LastNDays, vec![json!(7)])
+            .matches(&r)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_multi_value_operators() {
+        let r = row(json!("Red, Green"));
+        assert!(criteria(FilterOperator::HasAnyOf, vec![json!("green")])
+            .matches(&r)
+            .unwrap());
+        assert!(
+            !criteria(FilterOperator::HasAllOf, vec![json!("Red"), json!("Blue")])
+                .matches(&r)
+                .unwrap()
+        );
+        assert!(criteria(
+            FilterOperator::MultiIsEqual,
+            vec![json!("Green"), json!("Red")]
+        )
+        .matches(&r)
+        .unwrap());
+    }
+
+    #[test]
+    fn test_unsupported_operator() {
+        let r = row(json!("user@example.com"));
+        assert!(criteria(FilterOperator::IsCurrentUser, vec![])
+            .matches(&r)
+            .is_err());
+        assert!(criteria(FilterOperator::Equal, vec![]).matches(&r).is_err());
+    }
+}
@@ -0,0 +1,107 @@
+use crate::utils::is_default;
+
+use core::option::Option;
+
+use serde::{Deserialize, Serialize};
+
+/// Represents a [Cross-sheet Reference], which defines a range of cells in
+/// a *source* sheet that can be referenced by formulas in another sheet,
+/// for instance as `{Reference Name}`.
+///
+/// When the row Ids are omitted, the reference includes all rows for the
+/// specified columns.
+///
+/// [Cross-sheet Reference]: https://smartsheet.redoc.ly/#section/CrossSheetReference-Object
+///
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossSheetReference {
+    /// Cross-sheet reference Id, guaranteed unique within referencing sheet.
+    #[serde(skip_serializing_if = "is_default")]
+    pub id: u64,
+    /// Name of the cross-sheet reference, which is used in formulas.
+    pub name: String,
+    /// Sheet Id of the source sheet.
+    pub source_sheet_id: u64,
+    /// Defines the first row of the reference range.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_row_id: Option<u64>,
+    /// Defines the last row of the reference range.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_row_id: Option<u64>,
+    /// Defines the first column of the reference range.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_column_id: Option<u64>,
+    /// Defines the last column of the reference range.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column_id: Option<u64>,
+    /// Status of the cross-sheet reference.
+    #[serde(skip_serializing)]
+    pub status: Option<CrossSheetReferenceStatus>,
+}
+
+impl CrossSheetReference {
+    /// Create a new `CrossSheetReference` with a *name* and a *source sheet*
+    pub fn new<S: Into<String>>(name: S, source_sheet_id: u64) -> Self {
+        Self {
+            name: name.into(),
+            source_sheet_id,
+            ..Default::default()
+        }
+    }
+
+    /// Fluent setter for the column range of the reference
+    pub fn columns(mut self, start_column_id: u64, end_column_id: u64) -> Self {
+        self.start_column_id = Some(start_column_id);
+        self.end_column_id = Some(end_column_id);
+        self
+    }
+
+    /// Fluent setter for the row range of the reference
+    pub fn rows(mut self, start_row_id: u64, end_row_id: u64) -> Self {
+        self.start_row_id = Some(start_row_id);
+        self.end_row_id = Some(end_row_id);
+        self
+    }
+}
+
+/// Represents the status of a `CrossSheetReference`.
+///
+/// # Docs
+/// <https://smartsheet.redoc.ly/#section/CrossSheetReference-Object>
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CrossSheetReferenceStatus {
+    /// Reference is in a valid state.
+    Active,
+    /// Reference has been marked as blocked, for instance because the data
+    /// is too large to process.
+    Blocked,
+    /// Reference is broken, for example when the source sheet was deleted.
+    Broken,
+    /// Reference is disabled.
+    Disabled,
+    /// Reference is invalid.
+    Invalid,
+    /// The source sheet is not shared with the user.
+    NotShared,
+    /// Any status not recognized by this library.
+    #[serde(other)]
+    Unknown,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::from_str;
+
+    #[test]
+    fn test_deserialize_status() {
+        let status: CrossSheetReferenceStatus = from_str(r#""NOT_SHARED""#).unwrap();
+        assert_eq!(status, CrossSheetReferenceStatus::NotShared);
+
+        let status: CrossSheetReferenceStatus = from_str(r#""SOME_NEW_STATUS""#).unwrap();
+        assert_eq!(status, CrossSheetReferenceStatus::Unknown);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::models::Query;
+use crate::models::{Query, Row};
+use crate::types::Result;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -10,3 +12,63 @@ pub struct Filter {
     pub query: Query,
     pub version: Option<i64>,
 }
+
+impl Filter {
+    /// Apply the filter to a list of `Row`s, and return the rows which
+    /// match the filter `query`.
+    ///
+    /// Note: the `include_parent` setting of the query is not honored, so
+    /// parent rows of matching rows are not included in the result.
+    pub fn apply<'a>(&self, rows: &'a [Row]) -> Result<Vec<&'a Row>> {
+        let mut matched = Vec::new();
+
+        for row in rows {
+            if self.query.matches(row)? {
+                matched.push(row);
+            }
+        }
+
+        Ok(matched)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Cell;
+    use serde_json::json;
+
+    #[test]
+    fn test_apply() {
+        let filter: Filter = serde_json::from_value(json!({
+            "id": 1,
+            "filterType": "PERSONAL",
+            "name": "Open or Urgent",
+            "query": {
+                "operator": "OR",
+                "includeParent": false,
+                "criteria": [
+                    { "columnId": 1, "operator": "EQUAL", "values": ["Open"] },
+                    { "columnId": 2, "operator": "IS_CHECKED" }
+                ]
+            }
+        }))
+        .unwrap();
+
+        let rows: Vec<Row> = [("Open", false), ("Closed", true), ("Closed", false)]
+            .iter()
+            .enumerate()
+            .map(|(i, (status, urgent))| {
+                let cells: Vec<Cell> = serde_json::from_value(json!([
+                    { "columnId": 1, "value": status },
+                    { "columnId": 2, "value": urgent }
+                ]))
+                .unwrap();
+                Row::with_id_and_cells(i as u64, cells)
+            })
+            .collect();
+
+        let ids: Vec<u64> = filter.apply(&rows).unwrap().iter().map(|r| r.id).collect();
+        assert_eq!(ids, vec![0, 1]);
+    }
+}
@@ -0,0 +1,114 @@
+use core::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Defines the `FilterOperator` enum, along with the mapping of each
+/// variant to its name in the Smartsheet API.
+macro_rules! filter_operators {
+    ($($variant:ident => $name:literal,)*) => {
+        /// Represents the operator of a `Criteria` within a sheet filter.
+        ///
+        /// Any operator which is not known to this library is deserialized
+        /// as `FilterOperator::Unknown`, which keeps the original name so
+        /// that it is serialized unchanged.
+        ///
+        /// # Docs
+        /// <https://smartsheet.redoc.ly/#section/Criteria-Object>
+        ///
+        #[derive(Default, Debug, Clone, PartialEq)]
+        pub enum FilterOperator {
+            #[default]
+            $($variant,)*
+            Unknown(String),
+        }
+
+        impl FilterOperator {
+            /// Returns the name of the operator in the Smartsheet API, such
+            /// as `EQUAL`.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $name,)*
+                    Self::Unknown(name) => name,
+                }
+            }
+
+            /// Returns the operator for a `name` in the Smartsheet API.
+            fn from_name(name: String) -> Self {
+                match name.as_str() {
+                    $($name => Self::$variant,)*
+                    _ => Self::Unknown(name),
+                }
+            }
+        }
+    };
+}
+
+filter_operators! {
+    Equal => "EQUAL",
+    NotEqual => "NOT_EQUAL",
+    GreaterThan => "GREATER_THAN",
+    LessThan => "LESS_THAN",
+    GreaterThanOrEqual => "GREATER_THAN_OR_EQUAL",
+    LessThanOrEqual => "LESS_THAN_OR_EQUAL",
+    Contains => "CONTAINS",
+    DoesNotContain => "DOES_NOT_CONTAIN",
+    IsBlank => "IS_BLANK",
+    IsNotBlank => "IS_NOT_BLANK",
+    IsNumber => "IS_NUMBER",
+    IsNotNumber => "IS_NOT_NUMBER",
+    IsDate => "IS_DATE",
+    IsNotDate => "IS_NOT_DATE",
+    IsChecked => "IS_CHECKED",
+    IsNotChecked => "IS_NOT_CHECKED",
+    IsOneOf => "IS_ONE_OF",
+    IsNotOneOf => "IS_NOT_ONE_OF",
+    HasAnyOf => "HAS_ANY_OF",
+    HasNoneOf => "HAS_NONE_OF",
+    HasAllOf => "HAS_ALL_OF",
+    NotAllOf => "NOT_ALL_OF",
+    MultiIsEqual => "MULTI_IS_EQUAL",
+    MultiIsNotEqual => "MULTI_IS_NOT_EQUAL",
+    Between => "BETWEEN",
+    NotBetween => "NOT_BETWEEN",
+    Today => "TODAY",
+    NotToday => "NOT_TODAY",
+    Past => "PAST",
+    NotPast => "NOT_PAST",
+    Future => "FUTURE",
+    NotFuture => "NOT_FUTURE",
+    LastNDays => "LAST_N_DAYS",
+    NotLastNDays => "NOT_LAST_N_DAYS",
+    NextNDays => "NEXT_N_DAYS",
+    NotNextNDays => "NOT_NEXT_N_DAYS",
+    IsCurrentUser => "IS_CURRENT_USER",
+    IsNotCurrentUser => "IS_NOT_CURRENT_USER",
+}
+
+impl fmt::Display for FilterOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for FilterOperator {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for FilterOperator {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from_name)
+    }
+}
+
+/// Represents how the criteria of a `Query` are combined.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum QueryOperator {
+    /// All criteria must match.
+    #[default]
+    And,
+    /// Any one of the criteria must match.
+    Or,
+}
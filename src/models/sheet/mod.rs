@@ -1,20 +1,27 @@
 mod criteria;
+mod cross_sheet_reference;
 mod filter;
+mod filter_operator;
 mod gantt_config;
 mod heading;
 mod query;
+#[allow(clippy::module_inception)]
 mod sheet;
+mod sheet_publish;
 mod source;
 mod user_permissions;
 mod user_settings;
 mod workspace;
 
 pub use self::criteria::*;
+pub use self::cross_sheet_reference::*;
 pub use self::filter::*;
+pub use self::filter_operator::*;
 pub use self::gantt_config::*;
 pub use self::heading::*;
 pub use self::query::*;
 pub use self::sheet::*;
+pub use self::sheet_publish::*;
 pub use self::source::*;
 pub use self::user_permissions::*;
 pub use self::user_settings::*;
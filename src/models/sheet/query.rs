@@ -1,4 +1,6 @@
-use crate::models::Criteria;
+use crate::models::{Criteria, QueryOperator, Row};
+use crate::types::Result;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -6,5 +8,26 @@ use serde::{Deserialize, Serialize};
 pub struct Query {
     pub criteria: Vec<Criteria>,
     pub include_parent: bool,
-    pub operator: String,
+    pub operator: QueryOperator,
+}
+
+impl Query {
+    /// Evaluate the query against a `Row`, combining the result of each
+    /// `Criteria` with the query `operator`.
+    ///
+    /// Note: `include_parent` is not taken into account, as the parent of a
+    /// row is not known when evaluating a single `Row`.
+    pub fn matches(&self, row: &Row) -> Result<bool> {
+        for criteria in &self.criteria {
+            let matched = criteria.matches(row)?;
+
+            match self.operator {
+                QueryOperator::And if !matched => return Ok(false),
+                QueryOperator::Or if matched => return Ok(true),
+                _ => {}
+            }
+        }
+
+        Ok(self.operator == QueryOperator::And || self.criteria.is_empty())
+    }
 }
@@ -1,10 +1,11 @@
 use crate::models::{
-    Column, Filter, GanttConfig, Row, Source, UserPermissions, UserSettings, Workspace,
+    Column, CrossSheetReference, Filter, GanttConfig, Image, Row, Source, UserPermissions,
+    UserSettings, Workspace,
 };
 use crate::types::Result;
 
 use core::option::Option;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io::{Error, ErrorKind};
 
 use serde::{Deserialize, Serialize};
@@ -24,8 +25,10 @@ pub struct Sheet {
     pub columns: Vec<Column>,
     #[serde(default)]
     pub total_row_count: u64,
+    #[serde(default)]
     pub modified_at: String,
     pub access_level: String,
+    #[serde(default)]
     pub created_at: String,
     pub permalink: String,
     pub source: Option<Source>,
@@ -33,6 +36,7 @@ pub struct Sheet {
     pub filters: Option<Vec<Filter>>,
     pub version: Option<u64>,
     pub cell_image_upload_enabled: Option<bool>,
+    pub cross_sheet_references: Option<Vec<CrossSheetReference>>,
     pub dependencies_enabled: Option<bool>,
     #[serde(default)]
     pub effective_attachment_options: Vec<String>,
@@ -53,20 +57,61 @@ pub struct Sheet {
 impl Sheet {
     /// Retrieve a specified `Row` - for a given *row id* - from the `Sheet`
     pub fn get_row_by_id(&self, row_id: u64) -> Result<&Row> {
-        return match self.rows.iter().find(|row| row.id == row_id) {
+        match self.rows.iter().find(|row| row.id == row_id) {
             Some(row) => Ok(row),
             None => Err(Box::from(Error::new(
                 ErrorKind::NotFound,
                 "No row found for the given Row ID",
             ))),
-        };
+        }
     }
 
     /// Retrieve a mapping of *row id* to `Row` object.
     ///
     /// Note: this is likely more efficient when multiple `Row`s are to be
     /// retrieved based on their *row id*.
-    pub fn id_to_row(&self) -> RowIdToRow {
+    pub fn id_to_row(&self) -> RowIdToRow<'_> {
         self.rows.iter().map(|row| (row.id, row)).collect()
     }
+
+    /// Retrieve all the cell `Image`s in the `Sheet`, without duplicates.
+    ///
+    /// This is useful with `SmartsheetApi::get_image_urls`, to resolve the
+    /// temporary URLs for every image in a sheet in a single call.
+    pub fn images(&self) -> Vec<&Image> {
+        let mut seen: HashSet<&str> = HashSet::new();
+
+        self.rows
+            .iter()
+            .flat_map(|row| &row.cells)
+            .filter_map(|cell| cell.image.as_ref())
+            .filter(|image| seen.insert(image.id.as_str()))
+            .collect()
+    }
+
+    /// Apply a sheet filter - for a given filter *name* - to the `Sheet`
+    /// rows, and return the rows which match the filter.
+    ///
+    /// Note: filter definitions are only returned when the sheet is
+    /// retrieved with `SheetIncludeFlags::FilterDefinitions`.
+    pub fn filter_rows_by_name(&self, name: &str) -> Result<Vec<&Row>> {
+        let filters = match &self.filters {
+            Some(filters) => filters,
+            None => {
+                return Err(Box::from(Error::new(
+                    ErrorKind::NotFound,
+                    "No filters on the sheet; retrieve the sheet with \
+                    `SheetIncludeFlags::FilterDefinitions` to include them",
+                )));
+            }
+        };
+
+        match filters.iter().find(|f| f.name == name) {
+            Some(filter) => filter.apply(&self.rows),
+            None => Err(Box::from(Error::new(
+                ErrorKind::NotFound,
+                "No filter found for the given name",
+            ))),
+        }
+    }
 }
@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents the publish status of a sheet.
+///
+/// When setting the publish status, only the `*_enabled` flags - along with
+/// the optional `*_accessible_by` and `*_show_toolbar` settings - are
+/// used; the generated URLs are only populated in responses.
+///
+/// # Docs
+/// <https://smartsheet.redoc.ly/#section/SheetPublish-Object>
+///
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SheetPublish {
+    /// If true, a lightweight "Read Only" version of the sheet is
+    /// published.
+    pub read_only_lite_enabled: bool,
+    /// URL for the "Read Only" lightweight version of the sheet.
+    #[serde(skip_serializing)]
+    pub read_only_lite_url: Option<String>,
+    /// If true, a rich version of the sheet is published with the ability
+    /// to download row attachments and discussions.
+    pub read_only_full_enabled: bool,
+    /// Indicates who can access the "Read Only Full" view of the published
+    /// sheet; one of `ALL` or `ORG`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only_full_accessible_by: Option<PublishAccessibleBy>,
+    /// Indicates whether the left nav toolbar is displayed in the "Read
+    /// Only Full" view.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only_full_show_toolbar: Option<bool>,
+    /// URL for the "Read Only Full" view of the published sheet.
+    #[serde(skip_serializing)]
+    pub read_only_full_url: Option<String>,
+    /// If true, a rich version of the sheet is published with the ability
+    /// to edit cells and manage attachments and discussions.
+    pub read_write_enabled: bool,
+    /// Indicates who can access the "Edit by Anyone" view of the published
+    /// sheet; one of `ALL` or `ORG`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_write_accessible_by: Option<PublishAccessibleBy>,
+    /// Indicates whether the left nav toolbar is displayed in the "Edit by
+    /// Anyone" view.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_write_show_toolbar: Option<bool>,
+    /// URL for the "Edit by Anyone" view of the published sheet.
+    #[serde(skip_serializing)]
+    pub read_write_url: Option<String>,
+    /// If true, an iCal version of the sheet's calendar is published.
+    pub ical_enabled: bool,
+    /// URL for the iCal version of the published sheet.
+    #[serde(skip_serializing)]
+    pub ical_url: Option<String>,
+}
+
+impl SheetPublish {
+    /// Fluent setter for the `read_only_lite_enabled` attribute
+    pub fn read_only_lite(mut self, enabled: bool) -> Self {
+        self.read_only_lite_enabled = enabled;
+        self
+    }
+
+    /// Fluent setter for the `read_only_full_enabled` attribute
+    pub fn read_only_full(mut self, enabled: bool) -> Self {
+        self.read_only_full_enabled = enabled;
+        self
+    }
+
+    /// Fluent setter for the `read_write_enabled` attribute
+    pub fn read_write(mut self, enabled: bool) -> Self {
+        self.read_write_enabled = enabled;
+        self
+    }
+
+    /// Fluent setter for the `ical_enabled` attribute
+    pub fn ical(mut self, enabled: bool) -> Self {
+        self.ical_enabled = enabled;
+        self
+    }
+
+    /// Determine if the sheet is published in any format.
+    pub fn is_published(&self) -> bool {
+        self.read_only_lite_enabled
+            || self.read_only_full_enabled
+            || self.read_write_enabled
+            || self.ical_enabled
+    }
+}
+
+/// Indicates who can access a published sheet.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PublishAccessibleBy {
+    /// Anyone with the URL.
+    All,
+    /// Only users in the sheet owner's organization.
+    Org,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use serde_json::{from_str, to_string};
+
+    #[test]
+    fn test_deserialize() {
+        let json = indoc! {r#"
+            {
+              "readOnlyLiteEnabled": true,
+              "readOnlyLiteUrl": "https://publish.smartsheet.com/abc",
+              "readOnlyFullEnabled": false,
+              "readWriteEnabled": false,
+              "icalEnabled": false
+            }
+        "#};
+
+        let status: SheetPublish = from_str(json).unwrap();
+
+        assert!(status.is_published());
+        assert_eq!(
+            status.read_only_lite_url.as_deref(),
+            Some("https://publish.smartsheet.com/abc")
+        );
+    }
+
+    #[test]
+    fn test_serialize() {
+        let mut status = SheetPublish::default().read_write(true);
+        status.read_write_accessible_by = Some(PublishAccessibleBy::Org);
+        status.read_write_url = Some("https://publish.smartsheet.com/abc".to_owned());
+
+        assert_eq!(
+            to_string(&status).unwrap(),
+            r#"{"readOnlyLiteEnabled":false,"readOnlyFullEnabled":false,"readWriteEnabled":true,"readWriteAccessibleBy":"ORG","icalEnabled":false}"#
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod sheet_summary;
+mod summary_field;
+
+pub use self::sheet_summary::*;
+pub use self::summary_field::*;
@@ -0,0 +1,16 @@
+use crate::models::SummaryField;
+
+use serde::{Deserialize, Serialize};
+
+/// Represents the [Sheet Summary] for a sheet, which is a list of all the
+/// summary fields defined on the sheet.
+///
+/// [Sheet Summary]: https://smartsheet.redoc.ly/#section/Sheet-Summary-Object
+///
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SheetSummary {
+    /// Array of summary (or metadata) fields defined on the sheet.
+    #[serde(default)]
+    pub fields: Vec<SummaryField>,
+}
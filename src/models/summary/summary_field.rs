@@ -0,0 +1,209 @@
+use crate::models::{CellValue, ContactOption, Hyperlink, Image, User};
+use crate::utils::is_default;
+
+use core::option::Option;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Represents a [Summary Field] on a sheet, which is used to store sheet
+/// metadata such as project status, budget, or other KPIs.
+///
+/// [Summary Field]: https://smartsheet.redoc.ly/#section/Summary-Field-Object
+///
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SummaryField {
+    /// Summary Field Id
+    #[serde(skip_serializing_if = "is_default")]
+    pub id: u64,
+    /// Field index or position. This number is zero-based.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<u64>,
+    /// Arbitrary name, must be unique within summary.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub title: String,
+    /// One of the column types, such as `TEXT_NUMBER` or `CONTACT_LIST`.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_field: Option<String>,
+    /// Array of `ContactOption` objects to specify a pre-defined list of
+    /// values. Field type must be `CONTACT_LIST`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact_options: Option<Vec<ContactOption>>,
+    /// Time of creation.
+    #[serde(skip_serializing)]
+    pub created_at: Option<String>,
+    /// User object containing name and email of the creator of this
+    /// summary field.
+    #[serde(skip_serializing)]
+    pub created_by: Option<User>,
+    /// Visual representation of cell contents, as presented to the user in
+    /// the UI.
+    #[serde(skip_serializing)]
+    pub display_value: Option<String>,
+    /// The format descriptor. Only returned if the include query string
+    /// parameter contains `format` and this field has a non-default format
+    /// applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// The formula for the summary field, if set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formula: Option<String>,
+    /// A hyperlink to a dashboard, report, sheet, or URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hyperlink: Option<Hyperlink>,
+    /// Image object, if the summary field contains an image.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<Image>,
+    /// Indicates whether the field is locked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locked: Option<bool>,
+    /// Indicates whether the field is locked for the requesting user.
+    #[serde(skip_serializing)]
+    pub locked_for_user: Option<bool>,
+    /// Time of last modification.
+    #[serde(skip_serializing)]
+    pub modified_at: Option<String>,
+    /// User object containing name and email of the last person to modify
+    /// this summary field.
+    #[serde(skip_serializing)]
+    pub modified_by: Option<User>,
+    /// Represents the value of the summary field. For primitive values, this
+    /// can be retrieved as a `CellValue` via the `SummaryField.value` method.
+    ///
+    /// # More info
+    ///
+    /// - https://smartsheet.redoc.ly/#section/ObjectValue-Object
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_value: Option<Value>,
+    /// When applicable for `PICKLIST` field type. Array of the options
+    /// available for the field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<String>>,
+    /// When applicable for `PICKLIST` field type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    /// Indicates whether summary field values are restricted to the type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation: Option<bool>,
+}
+
+impl SummaryField {
+    /// Create a new `SummaryField` with a *title* and *type*, for instance
+    /// when adding summary fields to a sheet.
+    pub fn new<S: Into<String>>(title: S, type_field: S) -> Self {
+        Self {
+            title: title.into(),
+            type_field: Some(type_field.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a new `SummaryField` with an *id*, for instance when updating
+    /// summary fields on a sheet.
+    pub fn with_id(id: u64) -> Self {
+        Self {
+            id,
+            ..Default::default()
+        }
+    }
+
+    /// Retrieve the `object_value` as a `CellValue`, if it is a primitive
+    /// value - that is, a string, a number, or a Boolean.
+    pub fn value(&self) -> Option<CellValue> {
+        match &self.object_value {
+            Some(v @ (Value::String(_) | Value::Number(_) | Value::Bool(_))) => {
+                serde_json::from_value(v.to_owned()).ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// Fluent setter for the `object_value` attribute, from a primitive value
+    pub fn object_value<V: Into<CellValue>>(mut self, value: V) -> Self {
+        self.object_value = serde_json::to_value(value.into()).ok();
+        self
+    }
+
+    /// Fluent setter for the `format` attribute
+    pub fn format<S: Into<String>>(mut self, format: S) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+
+    /// Fluent setter for the `formula` attribute
+    pub fn formula<S: Into<String>>(mut self, formula: S) -> Self {
+        self.formula = Some(formula.into());
+        self
+    }
+
+    /// Fluent setter for the `index` attribute
+    pub fn index<U: Into<Option<u64>>>(mut self, index: U) -> Self {
+        self.index = index.into();
+        self
+    }
+
+    /// Fluent setter for the `locked` attribute
+    pub fn locked<B: Into<Option<bool>>>(mut self, locked: B) -> Self {
+        self.locked = locked.into();
+        self
+    }
+}
+
+impl From<SummaryField> for Vec<SummaryField> {
+    /// Useful when adding / updating summary field(s) on a sheet.
+    fn from(field: SummaryField) -> Self {
+        vec![field]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use serde_json::{from_str, to_string_pretty};
+
+    #[test]
+    fn test_deserialize_and_get_value() {
+        let json = indoc! {r#"
+            {
+              "id": 5629723890335620,
+              "index": 0,
+              "title": "Budget",
+              "type": "TEXT_NUMBER",
+              "locked": false,
+              "lockedForUser": false,
+              "objectValue": 1250.5,
+              "displayValue": "$1,250.50",
+              "createdAt": "2023-01-20T19:19:06Z",
+              "modifiedAt": "2023-01-20T19:19:06Z"
+            }
+        "#};
+
+        let field: SummaryField = from_str(json).unwrap();
+
+        assert_eq!(field.value(), Some(CellValue::from(1250.5)));
+        assert_eq!(field.display_value.as_deref(), Some("$1,250.50"));
+    }
+
+    #[test]
+    fn test_serialize() {
+        let field = SummaryField::new("Status", "TEXT_NUMBER")
+            .object_value("On Track")
+            .formula("=1+1");
+
+        assert_eq!(
+            to_string_pretty(&field).unwrap(),
+            indoc! {r#"
+                {
+                  "title": "Status",
+                  "type": "TEXT_NUMBER",
+                  "formula": "=1+1",
+                  "objectValue": "On Track"
+                }
+            "#}
+            .trim()
+        );
+    }
+}
@@ -0,0 +1,6 @@
+mod sheet_from_template;
+#[allow(clippy::module_inception)]
+mod template;
+
+pub use self::sheet_from_template::*;
+pub use self::template::*;
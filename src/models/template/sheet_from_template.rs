@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// Request body used to create a new sheet from a `Template`.
+///
+/// # Docs
+/// <https://smartsheet.redoc.ly/#operation/create-sheet-in-sheets-folder>
+///
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SheetFromTemplate {
+    /// Name of the new sheet.
+    pub name: String,
+    /// Template Id (or Sheet Id) to create the new sheet from.
+    pub from_id: u64,
+}
+
+impl SheetFromTemplate {
+    /// Create a new `SheetFromTemplate` with a sheet *name* and a
+    /// *template id*.
+    pub fn new<S: Into<String>>(name: S, from_id: u64) -> Self {
+        Self {
+            name: name.into(),
+            from_id,
+        }
+    }
+}
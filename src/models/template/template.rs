@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a [Template], which can be used to create a new sheet.
+///
+/// [Template]: https://smartsheet.redoc.ly/#section/Template-Object
+///
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Template {
+    /// Template Id.
+    pub id: u64,
+    /// Template name.
+    pub name: String,
+    /// User's permissions on the template; only set for user templates.
+    pub access_level: Option<String>,
+    /// Indicates whether the template is blank; only set for public
+    /// templates.
+    pub blank: Option<bool>,
+    /// Indicates whether the template is a global template; only set for
+    /// public templates.
+    pub global_template: Option<String>,
+    /// Template description.
+    pub description: Option<String>,
+    /// URL to the small preview image for this template; only set for
+    /// public templates.
+    pub image: Option<String>,
+    /// URL to the large preview image for this template; only set for
+    /// public templates.
+    pub large_image: Option<String>,
+    /// Locale of the template; only set for public templates.
+    pub locale: Option<String>,
+    /// Type of template, which is one of `report` or `sheet`.
+    #[serde(rename = "type")]
+    pub type_field: Option<String>,
+    /// Categories of the template; only set for public templates.
+    #[serde(default)]
+    pub categories: Vec<String>,
+    /// Tags of the template; only set for public templates.
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
@@ -0,0 +1,10 @@
+mod recipient;
+mod schedule;
+mod sent_update_request;
+#[allow(clippy::module_inception)]
+mod update_request;
+
+pub use self::recipient::*;
+pub use self::schedule::*;
+pub use self::sent_update_request::*;
+pub use self::update_request::*;
@@ -0,0 +1,44 @@
+use core::option::Option;
+
+use serde::{Deserialize, Serialize};
+
+/// Specifies the recipient of an email, such as an update request. The
+/// recipient may be either an individual or a group.
+///
+/// # Docs
+/// <https://smartsheet.redoc.ly/#section/Recipient-Object>
+///
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Recipient {
+    /// Email address of an individual recipient.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Id of a group recipient.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<u64>,
+}
+
+impl Recipient {
+    /// Create a new `Recipient` for an individual, from an *email address*
+    pub fn email<S: Into<String>>(email: S) -> Self {
+        Self {
+            email: Some(email.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a new `Recipient` for a group, from a *group id*
+    pub fn group(group_id: u64) -> Self {
+        Self {
+            group_id: Some(group_id),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&str> for Recipient {
+    fn from(email: &str) -> Self {
+        Self::email(email)
+    }
+}
@@ -0,0 +1,91 @@
+use core::option::Option;
+
+use serde::{Deserialize, Serialize};
+
+/// Specifies the recurrence of an update request.
+///
+/// # Docs
+/// <https://smartsheet.redoc.ly/#section/Schedule-Object>
+///
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Schedule {
+    /// Type of schedule.
+    #[serde(rename = "type")]
+    pub type_field: ScheduleType,
+    /// A list of days of the week (or `DAY`, `WEEKDAY`, `WEEKEND`) on which
+    /// the request is sent. Valid for `WEEKLY` and `MONTHLY` schedules.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day_descriptors: Option<Vec<String>>,
+    /// The day within the month. Valid for `MONTHLY` schedules.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day_of_month: Option<u64>,
+    /// Ordinal of the day within the month, one of `FIRST`, `SECOND`,
+    /// `THIRD`, `FOURTH`, or `LAST`. Valid for `MONTHLY` schedules.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day_ordinal: Option<String>,
+    /// The date, time, and time zone at which the delivery schedule ends,
+    /// in ISO-8601 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_at: Option<String>,
+    /// The date and time for when the last request was sent.
+    #[serde(skip_serializing)]
+    pub last_sent_at: Option<String>,
+    /// The date and time for when the next request is scheduled to send.
+    #[serde(skip_serializing)]
+    pub next_send_at: Option<String>,
+    /// Frequency on which the request is delivered. The unit is a function
+    /// of the `type` attribute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repeat_every: Option<u64>,
+    /// The date, time, and time zone at which the first delivery starts,
+    /// in ISO-8601 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_at: Option<String>,
+}
+
+impl Schedule {
+    /// Create a new `Schedule` of a specified *type*
+    pub fn new(type_field: ScheduleType) -> Self {
+        Self {
+            type_field,
+            ..Default::default()
+        }
+    }
+
+    /// Fluent setter for the `start_at` attribute
+    pub fn start_at<S: Into<String>>(mut self, start_at: S) -> Self {
+        self.start_at = Some(start_at.into());
+        self
+    }
+
+    /// Fluent setter for the `end_at` attribute
+    pub fn end_at<S: Into<String>>(mut self, end_at: S) -> Self {
+        self.end_at = Some(end_at.into());
+        self
+    }
+
+    /// Fluent setter for the `repeat_every` attribute
+    pub fn repeat_every<U: Into<Option<u64>>>(mut self, repeat_every: U) -> Self {
+        self.repeat_every = repeat_every.into();
+        self
+    }
+
+    /// Fluent setter for the `day_descriptors` attribute
+    pub fn day_descriptors<S: Into<String>>(mut self, days: impl IntoIterator<Item = S>) -> Self {
+        self.day_descriptors = Some(days.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+/// Represents the type of a `Schedule`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ScheduleType {
+    #[default]
+    Once,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
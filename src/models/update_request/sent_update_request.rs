@@ -0,0 +1,70 @@
+use crate::models::{Recipient, User};
+
+use core::option::Option;
+
+use serde::{Deserialize, Serialize};
+
+/// Represents a [Sent Update Request], which is a specific instance of an
+/// `UpdateRequest` that was delivered to a recipient.
+///
+/// [Sent Update Request]: https://smartsheet.redoc.ly/#section/SentUpdateRequest-Object
+///
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SentUpdateRequest {
+    /// Sent Update Request Id
+    pub id: u64,
+    /// Id of the originating update request.
+    pub update_request_id: u64,
+    /// Ids of rows included in the request.
+    #[serde(default)]
+    pub row_ids: Vec<u64>,
+    /// Ids of columns included in the request.
+    #[serde(default)]
+    pub column_ids: Vec<u64>,
+    /// Subject of the email.
+    pub subject: Option<String>,
+    /// Message body of the email.
+    pub message: Option<String>,
+    /// Indicates whether the attachments were included in the email.
+    pub include_attachments: Option<bool>,
+    /// Indicates whether the discussions were included in the email.
+    pub include_discussions: Option<bool>,
+    /// The date and time for when the request was sent to the recipient.
+    pub sent_at: Option<String>,
+    /// User object containing name and email of the sender.
+    pub sent_by: Option<User>,
+    /// Recipient the request was sent to.
+    pub sent_to: Option<Recipient>,
+    /// Status of the sent update request.
+    pub status: Option<SentUpdateRequestStatus>,
+}
+
+/// Represents the status of a `SentUpdateRequest`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SentUpdateRequestStatus {
+    Pending,
+    Complete,
+    Canceled,
+    /// Any other status returned by the API.
+    #[serde(other)]
+    Unknown,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::from_str;
+
+    #[test]
+    fn test_deserialize() {
+        let req: SentUpdateRequest =
+            from_str(r#"{"id": 123, "updateRequestId": 456, "status": "CANCELED"}"#).unwrap();
+        assert_eq!(req.status, Some(SentUpdateRequestStatus::Canceled));
+
+        let req: SentUpdateRequest =
+            from_str(r#"{"id": 123, "updateRequestId": 456, "status": "EXPIRED"}"#).unwrap();
+        assert_eq!(req.status, Some(SentUpdateRequestStatus::Unknown));
+    }
+}
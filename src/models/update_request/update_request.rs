@@ -0,0 +1,166 @@
+use crate::models::{Recipient, Schedule, User};
+use crate::utils::is_default;
+
+use core::option::Option;
+
+use serde::{Deserialize, Serialize};
+
+/// Represents an [Update Request], which is an email sent to one or more
+/// recipients asking them to update specific rows (and columns) in a sheet.
+///
+/// [Update Request]: https://smartsheet.redoc.ly/#section/UpdateRequest-Object
+///
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRequest {
+    /// Update Request Id
+    #[serde(skip_serializing_if = "is_default")]
+    pub id: u64,
+    /// Array of recipients to send the update request to.
+    #[serde(default)]
+    pub send_to: Vec<Recipient>,
+    /// Ids of rows to be included in the request.
+    #[serde(default)]
+    pub row_ids: Vec<u64>,
+    /// Ids of columns to be included in the request.
+    #[serde(default)]
+    pub column_ids: Vec<u64>,
+    /// Subject of the email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// Message body of the email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Indicates whether the sender should receive a copy of the email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cc_me: Option<bool>,
+    /// Indicates whether the attachments should be included in the email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_attachments: Option<bool>,
+    /// Indicates whether the discussions should be included in the email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_discussions: Option<bool>,
+    /// The schedule for which update requests are sent out. If omitted,
+    /// the request is sent immediately.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<Schedule>,
+    /// The date and time for when this request was originally created.
+    #[serde(skip_serializing)]
+    pub created_at: Option<String>,
+    /// The date and time for when the last change was made to this request.
+    #[serde(skip_serializing)]
+    pub modified_at: Option<String>,
+    /// User object containing name and email of the sender.
+    #[serde(skip_serializing)]
+    pub sent_by: Option<User>,
+}
+
+impl UpdateRequest {
+    /// Create a new `UpdateRequest` for a set of *rows*, which is to be
+    /// sent to a list of *recipients*.
+    pub fn new<R: Into<Recipient>>(
+        send_to: impl IntoIterator<Item = R>,
+        row_ids: impl Into<Vec<u64>>,
+    ) -> Self {
+        Self {
+            send_to: send_to.into_iter().map(Into::into).collect(),
+            row_ids: row_ids.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Fluent setter for the `column_ids` attribute
+    pub fn column_ids(mut self, column_ids: impl Into<Vec<u64>>) -> Self {
+        self.column_ids = column_ids.into();
+        self
+    }
+
+    /// Fluent setter for the `subject` attribute
+    pub fn subject<S: Into<String>>(mut self, subject: S) -> Self {
+        self.subject = Some(subject.into());
+        self
+    }
+
+    /// Fluent setter for the `message` attribute
+    pub fn message<S: Into<String>>(mut self, message: S) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Fluent setter for the `cc_me` attribute
+    pub fn cc_me<B: Into<Option<bool>>>(mut self, cc_me: B) -> Self {
+        self.cc_me = cc_me.into();
+        self
+    }
+
+    /// Fluent setter for the `include_attachments` attribute
+    pub fn include_attachments<B: Into<Option<bool>>>(mut self, include_attachments: B) -> Self {
+        self.include_attachments = include_attachments.into();
+        self
+    }
+
+    /// Fluent setter for the `include_discussions` attribute
+    pub fn include_discussions<B: Into<Option<bool>>>(mut self, include_discussions: B) -> Self {
+        self.include_discussions = include_discussions.into();
+        self
+    }
+
+    /// Fluent setter for the `schedule` attribute
+    pub fn schedule<S: Into<Option<Schedule>>>(mut self, schedule: S) -> Self {
+        self.schedule = schedule.into();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ScheduleType;
+    use indoc::indoc;
+    use serde_json::to_string_pretty;
+
+    #[test]
+    fn test_serialize() {
+        let req = UpdateRequest::new(["a@b.com"], [123, 456])
+            .column_ids([1])
+            .subject("Weekly status")
+            .include_attachments(true)
+            .include_discussions(false)
+            .schedule(
+                Schedule::new(ScheduleType::Weekly)
+                    .start_at("2023-02-06T09:00:00Z")
+                    .day_descriptors(["MONDAY"]),
+            );
+
+        assert_eq!(
+            to_string_pretty(&req).unwrap(),
+            indoc! {r#"
+                {
+                  "sendTo": [
+                    {
+                      "email": "a@b.com"
+                    }
+                  ],
+                  "rowIds": [
+                    123,
+                    456
+                  ],
+                  "columnIds": [
+                    1
+                  ],
+                  "subject": "Weekly status",
+                  "includeAttachments": true,
+                  "includeDiscussions": false,
+                  "schedule": {
+                    "type": "WEEKLY",
+                    "dayDescriptors": [
+                      "MONDAY"
+                    ],
+                    "startAt": "2023-02-06T09:00:00Z"
+                  }
+                }
+            "#}
+            .trim()
+        );
+    }
+}
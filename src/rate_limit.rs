@@ -0,0 +1,197 @@
+//! A client-side rate limiter, which makes requests wait until they fit
+//! within the Smartsheet API rate limit - rather than fail with a
+//! `429 Too Many Requests` error.
+//!
+//! The limit is about 300 requests per minute, per access token; certain
+//! resource-intensive operations - such as attaching a file, or getting
+//! cell history - count as 10 requests.
+//!
+//! # Docs
+//! - https://smartsheet.redoc.ly/#section/Work-at-Scale/Rate-Limiting
+//!
+use std::sync::Arc;
+use std::time::Duration;
+
+use hyper::Method;
+use tokio::sync::Mutex;
+use tokio::time::{sleep, Instant};
+
+/// Default number of requests per minute, per access token.
+pub const DEFAULT_REQUESTS_PER_MINUTE: u32 = 300;
+
+/// Default weight of a resource-intensive operation.
+pub const HEAVY_REQUEST_WEIGHT: u32 = 10;
+
+/// A token-bucket rate limiter, which can be set on a client via
+/// `SmartsheetApi::with_rate_limiter`.
+///
+/// Each request costs a number of tokens - its *weight* - which depends on
+/// the endpoint; a request waits until enough tokens are available.
+///
+/// Cloning a `RateLimiter` is cheap, and the clones share the same budget;
+/// so the same limiter can be used by multiple clients with the same
+/// access token.
+#[derive(Clone)]
+pub struct RateLimiter {
+    bucket: Arc<Mutex<Bucket>>,
+    capacity: f64,
+    per_second: f64,
+    weights: Arc<Vec<EndpointWeight>>,
+}
+
+/// The cost weight for requests to an endpoint.
+#[derive(Debug, Clone)]
+struct EndpointWeight {
+    method: Option<Method>,
+    path_segment: String,
+    weight: u32,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl RateLimiter {
+    /// Create a new `RateLimiter` which allows `requests_per_minute`, with
+    /// a burst of up to the same number of requests.
+    ///
+    /// Attaching a file and getting cell history have a weight of 10, and
+    /// all other requests have a weight of 1.
+    pub fn new(requests_per_minute: u32) -> Self {
+        let capacity = requests_per_minute.max(1) as f64;
+
+        Self {
+            bucket: Arc::new(Mutex::new(Bucket {
+                tokens: capacity,
+                updated_at: Instant::now(),
+            })),
+            capacity,
+            per_second: capacity / 60.0,
+            weights: Arc::new(vec![]),
+        }
+        .with_weight(Method::POST, "attachments", HEAVY_REQUEST_WEIGHT)
+        .with_weight(Method::GET, "history", HEAVY_REQUEST_WEIGHT)
+    }
+
+    /// Set the `weight` of requests with the HTTP `method` - or any method,
+    /// if `None` - to endpoints with a path that contains `path_segment`.
+    ///
+    /// For example, `with_weight(Method::GET, "history", 10)` applies to
+    /// `GET /sheets/{sheetId}/rows/{rowId}/columns/{columnId}/history`.
+    ///
+    /// Weights which are set later take precedence.
+    pub fn with_weight<M: Into<Option<Method>>>(
+        mut self,
+        method: M,
+        path_segment: &str,
+        weight: u32,
+    ) -> Self {
+        let mut weights = self.weights.as_ref().clone();
+        weights.insert(
+            0,
+            EndpointWeight {
+                method: method.into(),
+                path_segment: path_segment.to_owned(),
+                weight,
+            },
+        );
+        self.weights = Arc::new(weights);
+        self
+    }
+
+    /// Returns the weight of a request with the HTTP `method`, to `path`.
+    pub fn weight(&self, method: &Method, path: &str) -> u32 {
+        self.weights
+            .iter()
+            .find(|w| {
+                !matches!(&w.method, Some(m) if m != method)
+                    && path.split('/').any(|s| s == w.path_segment)
+            })
+            .map_or(1, |w| w.weight)
+    }
+
+    /// Wait until a request of the specified `weight` fits within the rate
+    /// limit, and then consume its tokens.
+    ///
+    /// Requests are granted in the order they call this method.
+    pub async fn acquire(&self, weight: u32) {
+        let weight = (weight as f64).min(self.capacity);
+
+        // The lock is held while waiting, so that requests are granted in
+        // order, and a heavy request isn't starved by lighter ones.
+        let mut bucket = self.bucket.lock().await;
+
+        self.refill(&mut bucket);
+
+        if bucket.tokens < weight {
+            let wait = (weight - bucket.tokens) / self.per_second;
+            sleep(Duration::from_secs_f64(wait)).await;
+            self.refill(&mut bucket);
+        }
+
+        bucket.tokens -= weight;
+    }
+
+    /// Add the tokens for the time elapsed since the last update.
+    fn refill(&self, bucket: &mut Bucket) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.updated_at).as_secs_f64();
+
+        bucket.tokens = (bucket.tokens + elapsed * self.per_second).min(self.capacity);
+        bucket.updated_at = now;
+    }
+}
+
+impl Default for RateLimiter {
+    /// Create a new `RateLimiter` which allows 300 requests per minute.
+    fn default() -> Self {
+        Self::new(DEFAULT_REQUESTS_PER_MINUTE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weight() {
+        let limiter = RateLimiter::default().with_weight(None, "cellimages", 5);
+
+        assert_eq!(limiter.weight(&Method::GET, "/2.0/sheets/1"), 1);
+        assert_eq!(limiter.weight(&Method::GET, "/2.0/sheets/1/attachments"), 1);
+        assert_eq!(
+            limiter.weight(&Method::POST, "/2.0/sheets/1/attachments"),
+            10
+        );
+        assert_eq!(
+            limiter.weight(&Method::GET, "/2.0/sheets/1/rows/2/columns/3/history"),
+            10
+        );
+        assert_eq!(
+            limiter.weight(&Method::POST, "/2.0/sheets/1/rows/2/columns/3/cellimages"),
+            5
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_acquire_waits() {
+        // 60 requests per minute, or 1 request per second
+        let limiter = RateLimiter::new(60);
+        let shared = limiter.clone();
+
+        let start = Instant::now();
+
+        limiter.acquire(50).await;
+        shared.acquire(10).await;
+        assert_eq!(start.elapsed().as_secs(), 0);
+
+        // The budget is shared, so this waits for 10 tokens to refill
+        shared.acquire(10).await;
+        assert_eq!(start.elapsed().as_secs(), 10);
+
+        limiter.acquire(1).await;
+        assert_eq!(start.elapsed().as_secs(), 11);
+    }
+}
@@ -0,0 +1,142 @@
+//! Regional presets for the Smartsheet API, which is hosted separately for
+//! Smartsheet (US), Smartsheet EU, and Smartsheetgov.
+//!
+use crate::constants::{API_ENDPOINT, API_ENDPOINT_EU, API_ENDPOINT_GOV, OAUTH_AUTHORIZE_URL};
+use crate::models::AttachmentType;
+
+use std::fmt;
+use std::io::{Error, ErrorKind};
+use std::str::FromStr;
+
+/// A Smartsheet region, which determines the API endpoint to use.
+///
+/// # Docs
+/// - https://smartsheet.redoc.ly/#section/Introduction/Base-URL
+///
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Region {
+    /// Smartsheet (`smartsheet.com`)
+    #[default]
+    Us,
+    /// Smartsheet EU (`smartsheet.eu`)
+    Eu,
+    /// Smartsheetgov (`smartsheetgov.com`)
+    Gov,
+}
+
+impl Region {
+    /// Returns the base API endpoint for the region.
+    pub fn api_endpoint(&self) -> &'static str {
+        match self {
+            Self::Us => API_ENDPOINT,
+            Self::Eu => API_ENDPOINT_EU,
+            Self::Gov => API_ENDPOINT_GOV,
+        }
+    }
+
+    /// Returns the URL to authorize a third-party app with OAuth 2.0, for
+    /// the region.
+    pub fn oauth_authorize_url(&self) -> &'static str {
+        match self {
+            Self::Us => OAUTH_AUTHORIZE_URL,
+            Self::Eu => "https://app.smartsheet.eu/b/authorize",
+            Self::Gov => "https://app.smartsheetgov.com/b/authorize",
+        }
+    }
+
+    /// Returns the URL to obtain an OAuth 2.0 access token, for the region.
+    pub fn oauth_token_url(&self) -> String {
+        format!("{}/token", self.api_endpoint())
+    }
+
+    /// Determine the region from a (custom) base API `endpoint`, defaulting
+    /// to `Region::Us` if it is not a known regional endpoint.
+    pub fn from_endpoint(endpoint: &str) -> Self {
+        let host = endpoint
+            .split("://")
+            .last()
+            .and_then(|rest| rest.split(&['/', ':'][..]).next())
+            .unwrap_or_default();
+
+        if host.ends_with("smartsheetgov.com") {
+            Self::Gov
+        } else if host.ends_with("smartsheet.eu") {
+            Self::Eu
+        } else {
+            Self::Us
+        }
+    }
+
+    /// Determine if an attachment of the specified type can be added in
+    /// the region.
+    ///
+    /// `Smartsheetgov.com` accounts are restricted to the following
+    /// attachment types: BOX_COM, FILE, GOOGLE_DRIVE, LINK, or ONEDRIVE.
+    pub fn allows_attachment_type(&self, attachment_type: &AttachmentType) -> bool {
+        match self {
+            Self::Gov => matches!(
+                attachment_type,
+                AttachmentType::BoxCom
+                    | AttachmentType::File
+                    | AttachmentType::GoogleDrive
+                    | AttachmentType::Link
+                    | AttachmentType::Onedrive
+            ),
+            _ => true,
+        }
+    }
+}
+
+impl FromStr for Region {
+    type Err = Error;
+
+    /// Parse a region - one of `US`, `EU`, or `GOV` - ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "US" => Ok(Self::Us),
+            "EU" => Ok(Self::Eu),
+            "GOV" => Ok(Self::Gov),
+            _ => Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("Unknown region `{}`, expected one of: US, EU, GOV", s),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Us => "US",
+            Self::Eu => "EU",
+            Self::Gov => "GOV",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("gov".parse::<Region>().unwrap(), Region::Gov);
+        assert_eq!(" EU ".parse::<Region>().unwrap(), Region::Eu);
+        assert!("mars".parse::<Region>().is_err());
+    }
+
+    #[test]
+    fn test_from_endpoint() {
+        assert_eq!(Region::from_endpoint(API_ENDPOINT), Region::Us);
+        assert_eq!(Region::from_endpoint(API_ENDPOINT_EU), Region::Eu);
+        assert_eq!(Region::from_endpoint(API_ENDPOINT_GOV), Region::Gov);
+        assert_eq!(Region::from_endpoint("http://127.0.0.1:8080"), Region::Us);
+    }
+
+    #[test]
+    fn test_allows_attachment_type() {
+        assert!(Region::Us.allows_attachment_type(&AttachmentType::Dropbox));
+        assert!(!Region::Gov.allows_attachment_type(&AttachmentType::Dropbox));
+        assert!(Region::Gov.allows_attachment_type(&AttachmentType::File));
+    }
+}
@@ -0,0 +1,49 @@
+//! Response metadata, such as the HTTP status and headers, which can be
+//! returned along with the deserialized data of a response.
+//!
+use std::time::Duration;
+
+use hyper::header::HeaderMap;
+use hyper::{Method, StatusCode};
+
+/// The deserialized `data` of a response, along with its metadata.
+///
+/// This is returned by `SmartsheetApi::with_meta`.
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    /// The deserialized response data.
+    pub data: T,
+    /// Metadata on the response, such as the HTTP status and headers.
+    pub meta: ResponseMeta,
+}
+
+impl<T> Response<T> {
+    /// Returns the deserialized response data, discarding the metadata.
+    pub fn into_data(self) -> T {
+        self.data
+    }
+}
+
+/// Metadata on a response from the Smartsheet API.
+#[derive(Debug, Clone)]
+pub struct ResponseMeta {
+    /// The HTTP method of the request.
+    pub method: Method,
+    /// The request URL.
+    pub url: String,
+    /// The HTTP status of the response.
+    pub status: StatusCode,
+    /// The response headers.
+    pub headers: HeaderMap,
+    /// The time taken for the request, until the response headers were
+    /// received.
+    pub elapsed: Duration,
+}
+
+impl ResponseMeta {
+    /// Retrieve the value of a response header, if it is present and is
+    /// valid as a string.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+}
@@ -1,6 +1,7 @@
 //! Utilities to validate a response to ensure that its *status code*
 //! indicates that it is a success.
 //!
+use crate::constants::{EXPIRED_TOKEN_ERROR_CODE, INVALID_TOKEN_ERROR_CODE};
 use crate::log::error;
 use crate::models::{RequestError, SmartsheetError};
 use crate::types::Result;
@@ -69,3 +70,15 @@ pub async fn raise_for_status(request_url: String, resp: &mut Response<Body>) ->
 
     Err(Box::new(e))
 }
+
+/// Check if the body of an error response indicates that the access token
+/// used for the request is invalid or has expired, so that a new token
+/// might succeed.
+pub(crate) fn is_rejected_token(resp_data: &[u8]) -> bool {
+    match serde_json::from_slice::<SmartsheetError>(resp_data) {
+        Ok(e) => {
+            e.error_code == INVALID_TOKEN_ERROR_CODE || e.error_code == EXPIRED_TOKEN_ERROR_CODE
+        }
+        Err(_) => false,
+    }
+}
@@ -0,0 +1,178 @@
+//! Per-request spans, which are emitted via the [tracing][] crate when the
+//! `tracing` feature is enabled.
+//!
+//! A span is opened for each call to the Smartsheet API, and is closed once
+//! the response has been deserialized. It has the following fields:
+//!     * `method`: the HTTP method of the request
+//!     * `endpoint`: the path of the request, with IDs redacted - for
+//!       example, `/2.0/sheets/{id}/rows`
+//!     * `sheet_id`: the ID of the sheet, if the request is for a sheet
+//!     * `attempt`: the attempt number, which is more than `1` when the
+//!       request is retried with a new access token
+//!     * `status`: the HTTP status of the response
+//!     * `error_code`: the Smartsheet error code, for an error response
+//!     * `network_ms`: the time taken to receive the response
+//!     * `deserialize_ms`: the time taken to deserialize the response data
+//!
+//! When the feature is disabled, these are all no-ops.
+//!
+//! [tracing]: https://docs.rs/tracing
+//!
+#![cfg_attr(not(feature = "tracing"), allow(unused_variables, dead_code))]
+
+use crate::models::RequestError;
+
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use hyper::{Body, Method, Response, StatusCode, Uri};
+
+/// The placeholder for an ID in the `endpoint` of a span.
+const ID_PLACEHOLDER: &str = "{id}";
+
+/// The span for a single call to the Smartsheet API.
+#[derive(Clone)]
+pub(crate) struct RequestSpan {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+}
+
+impl RequestSpan {
+    /// Open a new span for a request with a `method` and `uri`.
+    pub(crate) fn new(method: &Method, uri: &Uri) -> Self {
+        #[cfg(feature = "tracing")]
+        {
+            let path = uri.path();
+            let span = tracing::info_span!(
+                "smartsheet_request",
+                method = %method,
+                endpoint = %endpoint_template(path),
+                sheet_id = tracing::field::Empty,
+                attempt = tracing::field::Empty,
+                status = tracing::field::Empty,
+                error_code = tracing::field::Empty,
+                network_ms = tracing::field::Empty,
+                deserialize_ms = tracing::field::Empty,
+            );
+            if let Some(sheet_id) = sheet_id(path) {
+                span.record("sheet_id", sheet_id);
+            }
+
+            Self { span }
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        Self {}
+    }
+
+    /// Retrieve the span of a `Response`, if there is one.
+    pub(crate) fn of(resp: &Response<Body>) -> Option<Self> {
+        resp.extensions().get::<Self>().cloned()
+    }
+
+    /// Attach the span to a `Response`, so that it is kept open until
+    /// the response has been deserialized.
+    pub(crate) fn attach(&self, resp: &mut Response<Body>) {
+        #[cfg(feature = "tracing")]
+        resp.extensions_mut().insert(self.clone());
+    }
+
+    /// Run an `attempt` of the request, within the span.
+    pub(crate) async fn attempt<F: Future>(&self, attempt: u32, fut: F) -> F::Output {
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::Instrument;
+
+            self.span.record("attempt", attempt);
+            fut.instrument(self.span.clone()).await
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        fut.await
+    }
+
+    /// Record the `status` of a response, and the time `elapsed` to
+    /// receive it.
+    pub(crate) fn record_response(&self, status: StatusCode, elapsed: Duration) {
+        #[cfg(feature = "tracing")]
+        {
+            self.span.record("status", status.as_u16());
+            self.span.record("network_ms", millis(elapsed));
+        }
+    }
+
+    /// Record the Smartsheet error code of a `RequestError`.
+    pub(crate) fn record_error(&self, err: &RequestError) {
+        #[cfg(feature = "tracing")]
+        if let Some(error) = &err.error {
+            self.span.record("error_code", error.error_code);
+        }
+    }
+
+    /// Run `f` to deserialize the response data, within the span of the
+    /// response (if any), and record the time taken.
+    pub(crate) fn deserialize<T>(span: Option<Self>, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+
+        #[cfg(feature = "tracing")]
+        if let Some(span) = span {
+            let result = span.span.in_scope(f);
+            span.span.record("deserialize_ms", millis(start.elapsed()));
+            return result;
+        }
+
+        f()
+    }
+}
+
+/// Returns a `Duration` in milliseconds, with a fractional part.
+#[cfg(feature = "tracing")]
+fn millis(elapsed: Duration) -> f64 {
+    elapsed.as_secs_f64() * 1000.0
+}
+
+/// Returns the request `path`, with each ID replaced by a placeholder, so
+/// that requests to the same endpoint can be grouped together.
+pub(crate) fn endpoint_template(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            if !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()) {
+                ID_PLACEHOLDER
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Returns the ID of the sheet in a request `path`, if there is one.
+pub(crate) fn sheet_id(path: &str) -> Option<u64> {
+    let mut segments = path.split('/');
+
+    segments.find(|s| *s == "sheets")?;
+    segments.next()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_template() {
+        assert_eq!(
+            endpoint_template("/2.0/sheets/123/rows/456"),
+            "/2.0/sheets/{id}/rows/{id}"
+        );
+        assert_eq!(endpoint_template("/2.0/sheets"), "/2.0/sheets");
+        assert_eq!(endpoint_template("/2.0/serverinfo"), "/2.0/serverinfo");
+    }
+
+    #[test]
+    fn test_sheet_id() {
+        assert_eq!(sheet_id("/2.0/sheets/123/rows/456"), Some(123));
+        assert_eq!(sheet_id("/2.0/sheets/123"), Some(123));
+        assert_eq!(sheet_id("/2.0/sheets"), None);
+        assert_eq!(sheet_id("/2.0/reports/123"), None);
+    }
+}
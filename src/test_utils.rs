@@ -0,0 +1,337 @@
+//! Utilities for testing against a local HTTP(S) server (or proxy).
+//!
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// The certificate of the test CA, which issued the certificate of the
+/// HTTPS server.
+pub(crate) const TLS_CA_PEM: &[u8] = include_bytes!("../tests/fixtures/tls/ca.pem");
+
+/// The SHA-256 fingerprint of the certificate of the HTTPS server.
+pub(crate) const TLS_SERVER_FINGERPRINT: &str = "98:FB:D4:9F:94:22:BB:B8:42:18:3D:79:10:3B:70:5A:\
+    07:C3:5D:9F:43:EC:5B:2D:35:70:87:1A:8D:A1:39:2F";
+
+/// The certificate (for `localhost`) and private key of the HTTPS server.
+const TLS_SERVER_PEM: &[u8] = include_bytes!("../tests/fixtures/tls/localhost.pem");
+const TLS_SERVER_KEY: &[u8] = include_bytes!("../tests/fixtures/tls/localhost.key");
+
+/// A connection accepted by the server.
+trait Stream: Read + Write {}
+
+impl<S: Read + Write> Stream for S {}
+
+/// Accepts a TCP connection, or returns `None` to skip it.
+type Acceptor = Box<dyn Fn(TcpStream) -> Option<Box<dyn Stream>> + Send>;
+
+/// A minimal HTTP/1.1 server, which replies to each request in turn with
+/// one of the canned `(status, body)` responses, and records the raw
+/// requests it receives.
+pub(crate) struct MockServer {
+    addr: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockServer {
+    /// Start the server on a random local port, in a background thread.
+    pub(crate) fn start(responses: Vec<(u16, &'static str)>) -> Self {
+        Self::start_with_acceptor(responses, Box::new(|stream| Some(Box::new(stream))))
+    }
+
+    /// Start an HTTPS server on a random local port, with a certificate
+    /// for `localhost` issued by the test CA.
+    ///
+    /// Connections which fail the TLS handshake are skipped, without using
+    /// up one of the `responses`.
+    pub(crate) fn start_tls(responses: Vec<(u16, &'static str)>) -> Self {
+        Self::start_with_acceptor(responses, tls_acceptor())
+    }
+
+    fn start_with_acceptor(responses: Vec<(u16, &'static str)>, acceptor: Acceptor) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded = requests.clone();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                // Skip any connections which are closed by the client before
+                // a request is sent - such as when the TLS handshake fails
+                let (mut stream, request) = loop {
+                    let (stream, _) = listener.accept().unwrap();
+                    if let Some(mut stream) = acceptor(stream) {
+                        if let Ok(request) = read_request(&mut stream) {
+                            break (stream, request);
+                        }
+                    }
+                };
+
+                recorded.lock().unwrap().push(request);
+
+                let response = format!(
+                    "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\n\
+                    Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+                stream.flush().unwrap();
+            }
+        });
+
+        Self { addr, requests }
+    }
+
+    /// Returns the HTTPS URL for a `path` on the server, with the host
+    /// name in its certificate.
+    pub(crate) fn tls_url(&self, path: &str) -> String {
+        let port = self.addr.rsplit(':').next().unwrap();
+        format!("https://localhost:{}{}", port, path)
+    }
+
+    /// Returns the URL for a `path` on the server.
+    pub(crate) fn url(&self, path: &str) -> String {
+        format!("http://{}{}", self.addr, path)
+    }
+
+    /// Returns the address (`host:port`) of the server.
+    pub(crate) fn addr(&self) -> &str {
+        &self.addr
+    }
+
+    /// Returns the raw requests received by the server so far.
+    pub(crate) fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// A minimal HTTP proxy, which tunnels connections with the `CONNECT`
+/// method, and records the raw `CONNECT` requests it receives.
+pub(crate) struct MockProxy {
+    addr: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockProxy {
+    /// Start the proxy on a random local port, in a background thread.
+    pub(crate) fn start() -> Self {
+        Self::start_with_status(200)
+    }
+
+    /// Start a proxy which rejects each `CONNECT` request with a
+    /// `407 Proxy Authentication Required` response.
+    pub(crate) fn start_rejecting() -> Self {
+        Self::start_with_status(407)
+    }
+
+    fn start_with_status(status: u16) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded = requests.clone();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut client = stream.unwrap();
+                let mut reader = BufReader::new(client.try_clone().unwrap());
+
+                let mut request = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+
+                let target = request.split_whitespace().nth(1).unwrap().to_owned();
+                recorded.lock().unwrap().push(request);
+
+                if status != 200 {
+                    let response = format!("HTTP/1.1 {} Mock\r\nContent-Length: 0\r\n\r\n", status);
+                    client.write_all(response.as_bytes()).unwrap();
+                    continue;
+                }
+
+                let mut upstream = TcpStream::connect(target).unwrap();
+                client
+                    .write_all(b"HTTP/1.1 200 Connection Established\r\n\r\n")
+                    .unwrap();
+
+                let mut client_reader = client.try_clone().unwrap();
+                let mut upstream_writer = upstream.try_clone().unwrap();
+                thread::spawn(move || io::copy(&mut client_reader, &mut upstream_writer));
+                thread::spawn(move || io::copy(&mut upstream, &mut client));
+            }
+        });
+
+        Self { addr, requests }
+    }
+
+    /// Returns the URL of the proxy.
+    pub(crate) fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Returns the raw `CONNECT` requests received by the proxy so far.
+    pub(crate) fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// Read a raw HTTP/1.1 request from a `stream`.
+fn read_request(stream: &mut dyn Stream) -> io::Result<String> {
+    let mut reader = BufReader::new(stream);
+
+    let mut request = String::new();
+    let mut content_length = 0;
+
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap();
+            }
+        }
+        request.push_str(&line);
+        if line == "\r\n" {
+            break;
+        }
+    }
+
+    let mut data = vec![0; content_length];
+    reader.read_exact(&mut data)?;
+    request.push_str(&String::from_utf8_lossy(&data));
+
+    Ok(request)
+}
+
+/// Returns the `rustls` configuration of the HTTPS server.
+#[cfg(feature = "rust-tls")]
+fn tls_server_config() -> rustls::ServerConfig {
+    use rustls::{Certificate, PrivateKey, ServerConfig};
+
+    let certs = rustls_pemfile::certs(&mut &TLS_SERVER_PEM[..]).unwrap();
+    let key = rustls_pemfile::pkcs8_private_keys(&mut &TLS_SERVER_KEY[..])
+        .unwrap()
+        .remove(0);
+
+    ServerConfig::builder()
+        .with_safe_defaults()
+        .with_no_client_auth()
+        .with_single_cert(
+            certs.into_iter().map(Certificate).collect(),
+            PrivateKey(key),
+        )
+        .unwrap()
+}
+
+/// Returns an `Acceptor` which performs the TLS handshake for the HTTPS
+/// server, with `rustls`.
+#[cfg(feature = "rust-tls")]
+fn tls_acceptor() -> Acceptor {
+    use rustls::{ServerConnection, StreamOwned};
+
+    let config = Arc::new(tls_server_config());
+
+    Box::new(move |mut stream| {
+        let mut conn = ServerConnection::new(config.clone()).unwrap();
+        while conn.is_handshaking() {
+            conn.complete_io(&mut stream).ok()?;
+        }
+        Some(Box::new(StreamOwned::new(conn, stream)))
+    })
+}
+
+/// Returns an `Acceptor` which performs the TLS handshake for the HTTPS
+/// server, with `native-tls`.
+#[cfg(not(feature = "rust-tls"))]
+fn tls_acceptor() -> Acceptor {
+    use tokio_native_tls::native_tls::{Identity, TlsAcceptor};
+
+    let identity = Identity::from_pkcs8(TLS_SERVER_PEM, TLS_SERVER_KEY).unwrap();
+    let acceptor = TlsAcceptor::new(identity).unwrap();
+
+    Box::new(move |stream| Some(Box::new(acceptor.accept(stream).ok()?)))
+}
+
+/// An HTTPS server (built with `hyper`) which supports HTTP/1.1, and
+/// optionally HTTP/2, and records the HTTP version of each request it
+/// receives.
+#[cfg(feature = "rust-tls")]
+pub(crate) struct VersionServer {
+    addr: String,
+    versions: Arc<Mutex<Vec<hyper::Version>>>,
+}
+
+#[cfg(feature = "rust-tls")]
+impl VersionServer {
+    /// Start the server on a random local port, in a background task. If
+    /// `http2` is true, the server offers both `h2` and `http/1.1` via
+    /// ALPN, and otherwise only `http/1.1`.
+    pub(crate) async fn start(http2: bool, body: &'static str) -> Self {
+        use hyper::server::conn::Http;
+        use hyper::service::service_fn;
+        use hyper::{Body, Request, Response};
+        use std::convert::Infallible;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        let versions = Arc::new(Mutex::new(Vec::new()));
+
+        let mut config = tls_server_config();
+        config.alpn_protocols = match http2 {
+            true => vec![b"h2".to_vec(), b"http/1.1".to_vec()],
+            false => vec![b"http/1.1".to_vec()],
+        };
+        let acceptor = tokio_rustls::TlsAcceptor::from(Arc::new(config));
+
+        let recorded = versions.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let acceptor = acceptor.clone();
+                let recorded = recorded.clone();
+
+                tokio::spawn(async move {
+                    let stream = match acceptor.accept(stream).await {
+                        Ok(stream) => stream,
+                        Err(_) => return,
+                    };
+                    let service = service_fn(move |req: Request<Body>| {
+                        recorded.lock().unwrap().push(req.version());
+                        async move { Ok::<_, Infallible>(Response::new(Body::from(body))) }
+                    });
+
+                    let _ = Http::new()
+                        .http1_only(!http2)
+                        .serve_connection(stream, service)
+                        .await;
+                });
+            }
+        });
+
+        Self { addr, versions }
+    }
+
+    /// Returns the HTTPS URL for a `path` on the server, with the host
+    /// name in its certificate.
+    pub(crate) fn url(&self, path: &str) -> String {
+        let port = self.addr.rsplit(':').next().unwrap();
+        format!("https://localhost:{}{}", port, path)
+    }
+
+    /// Returns the HTTP versions of the requests received so far.
+    pub(crate) fn versions(&self) -> Vec<hyper::Version> {
+        self.versions.lock().unwrap().clone()
+    }
+}
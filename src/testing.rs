@@ -0,0 +1,439 @@
+//! Helpers for testing code which uses the client, which are available with
+//! the `testing` feature.
+//!
+//! A [`Cassette`] records the requests made to the Smartsheet API, and the
+//! responses received, to a JSON file. The file can then be replayed in
+//! tests which run offline - for example, in CI without an access token -
+//! while still exercising the deserialization of real responses.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use smartsheet_rs::testing::Cassette;
+//! use smartsheet_rs::SmartsheetApi;
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! // Records the responses to the file on the first run (which requires
+//! // an access token), and replays them on subsequent runs.
+//! let cassette = Cassette::auto("tests/cassettes/get_sheet.json")?;
+//! let token = std::env::var("SMARTSHEET_ACCESS_TOKEN").unwrap_or_default();
+//!
+//! let smart = SmartsheetApi::from_token(&token).with_middleware(cassette);
+//! let sheet = smart.get_sheet(1234567890).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+use crate::decompress::decompress;
+use crate::https::HttpsClient;
+use crate::middleware::Middleware;
+use crate::types::Result;
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use hyper::{Body, Request, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The local part of an email address, once it is scrubbed.
+const SCRUBBED_EMAIL_USER: &str = "user";
+/// The domain of an email address, once it is scrubbed.
+const SCRUBBED_EMAIL_DOMAIN: &str = "example.com";
+
+/// The contents of a cassette file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CassetteData {
+    pub interactions: Vec<Interaction>,
+}
+
+/// A request to the Smartsheet API, and the response received.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+/// A recorded request. The `Authorization` header, and other headers, are
+/// not recorded.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    /// The path and query of the request URL, such as `/2.0/sheets/123`.
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub body: Value,
+}
+
+/// A recorded response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub body: Value,
+}
+
+/// Whether a `Cassette` records or replays interactions.
+enum Mode {
+    Record,
+    Replay,
+}
+
+/// A **Cassette** is a `Middleware` which either records the interactions
+/// of a client with the Smartsheet API to a JSON file, or replays them from
+/// the file without making any requests.
+///
+/// Email addresses are scrubbed from the recorded URLs, headers, and
+/// bodies, and the access token is never recorded.
+///
+/// Interactions are replayed by matching the method and URL (ignoring the
+/// host) of each request, in the order that they were recorded.
+///
+/// # Note
+/// When recording, requests are made with the HTTP client of the
+/// `SmartsheetApi` - and so its proxy, TLS configuration and HTTP version -
+/// but they bypass the rate limiter of the client (if any).
+pub struct Cassette {
+    path: PathBuf,
+    mode: Mode,
+    data: Mutex<CassetteData>,
+    replayed: Mutex<Vec<bool>>,
+}
+
+impl Cassette {
+    /// Create a new `Cassette` which records interactions to the file at
+    /// `path`, replacing it if it exists.
+    ///
+    /// The file is saved after each interaction.
+    pub fn record(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            mode: Mode::Record,
+            data: Mutex::default(),
+            replayed: Mutex::default(),
+        }
+    }
+
+    /// Create a new `Cassette` which replays the interactions in the file
+    /// at `path`.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or parsed.
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let data: CassetteData = serde_json::from_slice(&fs::read(&path)?)?;
+        let replayed = vec![false; data.interactions.len()];
+
+        Ok(Self {
+            path,
+            mode: Mode::Replay,
+            data: Mutex::new(data),
+            replayed: Mutex::new(replayed),
+        })
+    }
+
+    /// Create a new `Cassette` which replays the interactions in the file
+    /// at `path` if it exists, and records them otherwise.
+    pub fn auto(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+
+        if path.exists() {
+            Self::replay(path)
+        } else {
+            Ok(Self::record(path))
+        }
+    }
+
+    /// Returns the path to the cassette file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns `true` if the cassette is recording interactions.
+    pub fn is_recording(&self) -> bool {
+        matches!(self.mode, Mode::Record)
+    }
+
+    /// Returns the interactions which were recorded, or loaded from the
+    /// cassette file.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.data.lock().unwrap().interactions.clone()
+    }
+
+    /// Send a request with the HTTP client of the `SmartsheetApi`, and
+    /// record the interaction.
+    async fn record_request(&self, req: &mut Request<Body>) -> Result<Response<Body>> {
+        // The client which sends the request is passed in its extensions
+        let client = req
+            .extensions()
+            .get::<HttpsClient>()
+            .cloned()
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::Unsupported,
+                    "A recording `Cassette` must be used as the middleware of a `SmartsheetApi`",
+                )
+            })?;
+
+        let body = hyper::body::to_bytes(req.body_mut()).await?;
+
+        let mut copy = Request::new(Body::from(body.clone()));
+        *copy.method_mut() = req.method().clone();
+        *copy.uri_mut() = req.uri().clone();
+        *copy.headers_mut() = req.headers().clone();
+
+        let res = decompress(client.request(copy).await?).await?;
+
+        let (parts, res_body) = res.into_parts();
+        let res_body = hyper::body::to_bytes(res_body).await?;
+
+        let headers = parts
+            .headers
+            .iter()
+            .filter_map(|(name, value)| {
+                let value = value.to_str().ok()?;
+                Some((name.to_string(), scrub_emails(value)))
+            })
+            .collect();
+
+        let interaction = Interaction {
+            request: RecordedRequest {
+                method: req.method().to_string(),
+                uri: request_uri(req),
+                body: body_to_value(&body),
+            },
+            response: RecordedResponse {
+                status: parts.status.as_u16(),
+                headers,
+                body: body_to_value(&res_body),
+            },
+        };
+
+        {
+            let mut data = self.data.lock().unwrap();
+            data.interactions.push(interaction);
+            self.save(&data)?;
+        }
+
+        Ok(Response::from_parts(parts, Body::from(res_body)))
+    }
+
+    /// Returns the recorded response for a request, marking it as replayed.
+    fn replay_request(&self, req: &Request<Body>) -> Result<Response<Body>> {
+        let method = req.method().as_str();
+        let uri = request_uri(req);
+
+        let data = self.data.lock().unwrap();
+        let mut replayed = self.replayed.lock().unwrap();
+
+        let index = data
+            .interactions
+            .iter()
+            .enumerate()
+            .position(|(i, interaction)| {
+                !replayed[i]
+                    && interaction.request.method == method
+                    && interaction.request.uri == uri
+            })
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::NotFound,
+                    format!(
+                        "No recorded interaction for `{} {}` in cassette {:?}",
+                        method, uri, self.path
+                    ),
+                )
+            })?;
+
+        replayed[index] = true;
+
+        let recorded = &data.interactions[index].response;
+
+        let mut res = Response::builder().status(StatusCode::from_u16(recorded.status)?);
+        for (name, value) in &recorded.headers {
+            res = res.header(name, value);
+        }
+
+        Ok(res.body(value_to_body(&recorded.body))?)
+    }
+
+    /// Save the cassette `data` to the file.
+    fn save(&self, data: &CassetteData) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&self.path, serde_json::to_string_pretty(data)?)?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Middleware for Cassette {
+    async fn before_request(&self, req: &mut Request<Body>) -> Result<Option<Response<Body>>> {
+        let res = match &self.mode {
+            Mode::Record => self.record_request(req).await?,
+            Mode::Replay => self.replay_request(req)?,
+        };
+
+        Ok(Some(res))
+    }
+}
+
+/// Returns the path and query of a request URL, with emails scrubbed.
+fn request_uri(req: &Request<Body>) -> String {
+    let uri = req
+        .uri()
+        .path_and_query()
+        .map_or_else(|| req.uri().path(), |p| p.as_str());
+
+    scrub_emails(uri)
+}
+
+/// Returns the (email-scrubbed) body as JSON data, if possible, and as a
+/// string otherwise.
+fn body_to_value(body: &[u8]) -> Value {
+    if body.is_empty() {
+        return Value::Null;
+    }
+
+    let text = scrub_emails(&String::from_utf8_lossy(body));
+
+    serde_json::from_str(&text).unwrap_or(Value::String(text))
+}
+
+/// Returns the body for a recorded JSON `value`.
+fn value_to_body(value: &Value) -> Body {
+    match value {
+        Value::Null => Body::empty(),
+        Value::String(text) => Body::from(text.to_owned()),
+        value => Body::from(value.to_string()),
+    }
+}
+
+/// Replace each email address in `text` - including URI-encoded ones, such
+/// as `john.doe%40example.org` - with a placeholder address.
+pub fn scrub_emails(text: &str) -> String {
+    let is_local = |b: u8| b.is_ascii_alphanumeric() || b"._%+-".contains(&b);
+    let is_domain = |b: u8| b.is_ascii_alphanumeric() || b".-".contains(&b);
+
+    let bytes = text.as_bytes();
+    let mut scrubbed = String::with_capacity(text.len());
+    let mut last = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let separator = if bytes[i] == b'@' {
+            "@"
+        } else if bytes[i..].starts_with(b"%40") {
+            "%40"
+        } else {
+            i += 1;
+            continue;
+        };
+
+        let mut start = i;
+        while start > last && is_local(bytes[start - 1]) {
+            start -= 1;
+        }
+
+        let domain_start = i + separator.len();
+        let mut end = domain_start;
+        while end < bytes.len() && is_domain(bytes[end]) {
+            end += 1;
+        }
+        while end > domain_start && bytes[end - 1] == b'.' {
+            end -= 1;
+        }
+
+        if start < i && text[domain_start..end].contains('.') {
+            scrubbed.push_str(&text[last..start]);
+            scrubbed.push_str(SCRUBBED_EMAIL_USER);
+            scrubbed.push_str(separator);
+            scrubbed.push_str(SCRUBBED_EMAIL_DOMAIN);
+            last = end;
+            i = end;
+        } else {
+            i = domain_start;
+        }
+    }
+
+    scrubbed.push_str(&text[last..]);
+
+    scrubbed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{MockServer, TLS_CA_PEM};
+    use crate::tls::TlsConfig;
+    use crate::SmartsheetApi;
+
+    #[test]
+    fn test_scrub_emails() {
+        assert_eq!(
+            scrub_emails(r#"{"email": "john.doe+1@corp.example.org", "x": "a@b"}"#),
+            r#"{"email": "user@example.com", "x": "a@b"}"#
+        );
+        assert_eq!(
+            scrub_emails("/2.0/users?email=jane_doe%40corp.org."),
+            "/2.0/users?email=user%40example.com."
+        );
+        assert_eq!(scrub_emails("no emails here"), "no emails here");
+    }
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        // The server is only trusted with the TLS configuration of the
+        // client, which is used to record the interactions
+        let server = MockServer::start_tls(vec![(
+            200,
+            r#"{"id": 123, "name": "My Sheet", "accessLevel": "OWNER", "permalink": "https://a.b/c", "owner": "john.doe@corp.org"}"#,
+        )]);
+
+        let path = std::env::temp_dir().join(format!(
+            "smartsheet-rs-cassette-{}.json",
+            std::process::id()
+        ));
+
+        let tls = TlsConfig::new()
+            .add_root_certificates_pem(TLS_CA_PEM)
+            .unwrap();
+        let endpoint = server.tls_url("/2.0");
+        let smart = SmartsheetApi::from_endpoint_and_token(&endpoint, "my-token")
+            .with_proxy(None)
+            .with_tls_config(tls)
+            .unwrap()
+            .with_middleware(Cassette::record(&path));
+
+        let sheet = smart.get_sheet(123).await.unwrap();
+        assert_eq!(sheet.owner.as_deref(), Some("john.doe@corp.org"));
+
+        let saved = fs::read_to_string(&path).unwrap();
+        assert!(!saved.contains("my-token"));
+        assert!(!saved.contains("john.doe"));
+
+        let cassette = Cassette::replay(&path).unwrap();
+        assert!(!cassette.is_recording());
+        assert_eq!(cassette.interactions()[0].request.uri, "/2.0/sheets/123");
+
+        let smart = SmartsheetApi::from_token("").with_middleware(cassette);
+
+        let sheet = smart.get_sheet(123).await.unwrap();
+        assert_eq!(sheet.name, "My Sheet");
+        assert_eq!(sheet.owner.as_deref(), Some("user@example.com"));
+
+        let err = smart.get_sheet(123).await.unwrap_err();
+        assert!(err.to_string().starts_with("No recorded interaction"));
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,540 @@
+//! Custom TLS configuration for the connections to the Smartsheet API.
+//!
+//! By default, the client trusts the root certificates of the platform,
+//! and uses the default protocol versions of the TLS implementation. A
+//! `TlsConfig` can be used to - for example - trust a private CA, such as
+//! for a TLS-inspecting gateway, pin the certificate of the server, or
+//! choose the protocol versions.
+//!
+//! This works with both the `rust-tls` and `native-tls` features; the
+//! TLS implementation in use is re-exported from this module, so that a
+//! configuration can also be built with it directly.
+//!
+use crate::https::tls::{HttpsConnector, MaybeHttpsStream};
+use crate::proxy::ProxyConnector;
+use crate::types::Result;
+
+use std::fmt;
+use std::future::Future;
+use std::io::{Error, ErrorKind};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use hyper::service::Service;
+use hyper::Uri;
+use sha2::{Digest, Sha256};
+use tokio::net::TcpStream;
+
+#[cfg(feature = "rust-tls")]
+pub use rustls;
+#[cfg(not(feature = "rust-tls"))]
+pub use tokio_native_tls::native_tls;
+
+/// The SHA-256 fingerprint of a certificate.
+type Fingerprint = [u8; 32];
+
+/// A version of the TLS protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TlsVersion {
+    Tls12,
+    Tls13,
+}
+
+/// A custom **TlsConfig** for the connections to the Smartsheet API.
+///
+/// # Example
+///
+/// ```no_run
+/// use smartsheet_rs::tls::{TlsConfig, TlsVersion};
+/// use smartsheet_rs::SmartsheetApi;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// let pem = std::fs::read("gateway-ca.pem")?;
+///
+/// let tls = TlsConfig::new()
+///     .add_root_certificates_pem(&pem)?
+///     .with_min_version(TlsVersion::Tls12);
+///
+/// let smart = SmartsheetApi::from_env()?.with_tls_config(tls)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct TlsConfig {
+    root_certificates: Vec<Vec<u8>>,
+    without_native_roots: bool,
+    pins: Vec<Fingerprint>,
+    min_version: Option<TlsVersion>,
+    max_version: Option<TlsVersion>,
+    #[cfg(feature = "rust-tls")]
+    rustls: Option<rustls::ClientConfig>,
+    #[cfg(not(feature = "rust-tls"))]
+    native_tls: Option<native_tls::TlsConnector>,
+}
+
+impl TlsConfig {
+    /// Create a new `TlsConfig`, which is the same as the default
+    /// configuration of the client.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a `TlsConfig` from a `rustls::ClientConfig`.
+    ///
+    /// The root certificates and protocol versions of the `config` are
+    /// used as-is, and so only the pinned certificates (if any) are added
+    /// to it. The ALPN protocols are set by the client.
+    #[cfg(feature = "rust-tls")]
+    pub fn from_rustls(config: rustls::ClientConfig) -> Self {
+        Self {
+            rustls: Some(config),
+            ..Self::default()
+        }
+    }
+
+    /// Create a `TlsConfig` from a `native_tls::TlsConnector`.
+    ///
+    /// The root certificates and protocol versions of the `connector` are
+    /// used as-is, and so only the pinned certificates (if any) are added
+    /// to it.
+    #[cfg(not(feature = "rust-tls"))]
+    pub fn from_native_tls(connector: native_tls::TlsConnector) -> Self {
+        Self {
+            native_tls: Some(connector),
+            ..Self::default()
+        }
+    }
+
+    /// Trust the root certificates in a `pem` bundle, in addition to the
+    /// root certificates of the platform.
+    ///
+    /// # Errors
+    /// Returns an error if the bundle does not contain any certificates,
+    /// or one of them is invalid.
+    pub fn add_root_certificates_pem(mut self, pem: &[u8]) -> Result<Self> {
+        let certificates = parse_pem_certificates(pem)?;
+        if certificates.is_empty() {
+            return Err(Box::new(Error::new(
+                ErrorKind::InvalidData,
+                "No certificates were found in the PEM data",
+            )));
+        }
+        self.root_certificates.extend(certificates);
+
+        Ok(self)
+    }
+
+    /// Trust a root certificate in the DER format, in addition to the root
+    /// certificates of the platform.
+    pub fn add_root_certificate_der(mut self, der: impl Into<Vec<u8>>) -> Self {
+        self.root_certificates.push(der.into());
+        self
+    }
+
+    /// Trust *only* the root certificates which are added to the config,
+    /// and not the root certificates of the platform.
+    pub fn without_native_roots(mut self) -> Self {
+        self.without_native_roots = true;
+        self
+    }
+
+    /// Pin the certificate of the server, by the SHA-256 `fingerprint` of
+    /// the certificate in hex - for example, the output of:
+    ///
+    /// ```text
+    /// openssl x509 -in cert.pem -noout -fingerprint -sha256
+    /// ```
+    ///
+    /// Once a certificate is pinned, connections are only made to servers
+    /// which present one of the pinned certificates, and so never without
+    /// TLS. This is checked in addition to the usual verification of the
+    /// certificate.
+    ///
+    /// # Errors
+    /// Returns an error if the `fingerprint` is not a valid SHA-256 hash;
+    /// colons and the case of the hex digits are ignored.
+    pub fn with_pinned_certificate(mut self, fingerprint: &str) -> Result<Self> {
+        self.pins.push(parse_fingerprint(fingerprint)?);
+        Ok(self)
+    }
+
+    /// Set the minimum version of the TLS protocol to use.
+    pub fn with_min_version(mut self, version: TlsVersion) -> Self {
+        self.min_version = Some(version);
+        self
+    }
+
+    /// Set the maximum version of the TLS protocol to use.
+    pub fn with_max_version(mut self, version: TlsVersion) -> Self {
+        self.max_version = Some(version);
+        self
+    }
+
+    /// Returns true if `version` is within the min and max versions.
+    #[cfg(feature = "rust-tls")]
+    fn allows(&self, version: TlsVersion) -> bool {
+        !matches!(self.min_version, Some(min) if version < min)
+            && !matches!(self.max_version, Some(max) if version > max)
+    }
+
+    /// Build the settings for the TLS implementation in use.
+    #[cfg(feature = "rust-tls")]
+    pub(crate) fn build(&self) -> Result<TlsSettings> {
+        use rustls::{Certificate, ClientConfig, RootCertStore};
+
+        let config = match &self.rustls {
+            Some(config) => config.clone(),
+            None => {
+                let mut roots = RootCertStore::empty();
+                if !self.without_native_roots {
+                    for cert in rustls_native_certs::load_native_certs()? {
+                        // Skip any invalid certificates of the platform, as
+                        // `hyper-rustls` does
+                        let _ = roots.add(&Certificate(cert.0));
+                    }
+                }
+                for der in &self.root_certificates {
+                    roots.add(&Certificate(der.clone()))?;
+                }
+                if roots.is_empty() {
+                    return Err(Box::new(Error::new(
+                        ErrorKind::InvalidInput,
+                        "No root certificates are trusted",
+                    )));
+                }
+
+                let versions: Vec<_> = [
+                    (TlsVersion::Tls12, &rustls::version::TLS12),
+                    (TlsVersion::Tls13, &rustls::version::TLS13),
+                ]
+                .into_iter()
+                .filter(|(version, _)| self.allows(*version))
+                .map(|(_, version)| version)
+                .collect();
+
+                ClientConfig::builder()
+                    .with_safe_default_cipher_suites()
+                    .with_safe_default_kx_groups()
+                    .with_protocol_versions(&versions)?
+                    .with_root_certificates(roots)
+                    .with_no_client_auth()
+            }
+        };
+
+        Ok(TlsSettings {
+            config,
+            pins: Arc::new(self.pins.clone()),
+        })
+    }
+
+    /// Build the settings for the TLS implementation in use.
+    #[cfg(not(feature = "rust-tls"))]
+    pub(crate) fn build(&self) -> Result<TlsSettings> {
+        use native_tls::{Certificate, Protocol};
+
+        let connector = match &self.native_tls {
+            Some(connector) => connector.clone(),
+            None => {
+                let protocol = |version| match version {
+                    TlsVersion::Tls12 => Protocol::Tlsv12,
+                    TlsVersion::Tls13 => Protocol::Tlsv13,
+                };
+
+                let mut builder = native_tls::TlsConnector::builder();
+                builder.disable_built_in_roots(self.without_native_roots);
+                for der in &self.root_certificates {
+                    builder.add_root_certificate(Certificate::from_der(der)?);
+                }
+                builder.min_protocol_version(self.min_version.map(protocol));
+                builder.max_protocol_version(self.max_version.map(protocol));
+
+                builder.build()?
+            }
+        };
+
+        Ok(TlsSettings {
+            connector,
+            pins: Arc::new(self.pins.clone()),
+        })
+    }
+}
+
+impl fmt::Debug for TlsConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TlsConfig")
+            .field("root_certificates", &self.root_certificates.len())
+            .field("without_native_roots", &self.without_native_roots)
+            .field("pins", &self.pins.len())
+            .field("min_version", &self.min_version)
+            .field("max_version", &self.max_version)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A `TlsConfig` which has been built for the TLS implementation in use.
+#[derive(Clone)]
+pub(crate) struct TlsSettings {
+    #[cfg(feature = "rust-tls")]
+    pub(crate) config: rustls::ClientConfig,
+    #[cfg(not(feature = "rust-tls"))]
+    pub(crate) connector: native_tls::TlsConnector,
+    pub(crate) pins: Arc<Vec<Fingerprint>>,
+}
+
+impl fmt::Debug for TlsSettings {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TlsSettings")
+            .field("pins", &self.pins.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A connector which opens a connection with the HTTPS connector, and then
+/// checks the certificate of the server against the pinned certificates
+/// (if any).
+#[derive(Clone)]
+pub(crate) struct PinningConnector {
+    https: HttpsConnector<ProxyConnector>,
+    pins: Arc<Vec<Fingerprint>>,
+}
+
+impl PinningConnector {
+    /// Create a new `PinningConnector`, which only allows connections to
+    /// servers with one of the `pins`, unless it is empty.
+    pub(crate) fn new(https: HttpsConnector<ProxyConnector>, pins: Arc<Vec<Fingerprint>>) -> Self {
+        Self { https, pins }
+    }
+}
+
+impl Service<Uri> for PinningConnector {
+    type Response = MaybeHttpsStream<TcpStream>;
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.https.poll_ready(cx)
+    }
+
+    fn call(&mut self, dst: Uri) -> Self::Future {
+        let connecting = self.https.call(dst);
+        let pins = self.pins.clone();
+
+        Box::pin(async move {
+            let stream = connecting.await?;
+
+            if !pins.is_empty() {
+                // A connection without a server certificate - such as one
+                // which does not use TLS - can never match a pin
+                let der = peer_certificate(&stream)?.ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::PermissionDenied,
+                        "The server did not present a certificate to match the pinned certificates",
+                    )
+                })?;
+                let fingerprint = Fingerprint::from(Sha256::digest(&der));
+
+                if !pins.contains(&fingerprint) {
+                    return Err(Error::new(
+                        ErrorKind::PermissionDenied,
+                        "The server certificate does not match a pinned certificate",
+                    )
+                    .into());
+                }
+            }
+
+            Ok(stream)
+        })
+    }
+}
+
+/// Returns the certificate of the server (in the DER format), or `None`
+/// for a connection which does not use TLS.
+#[cfg(feature = "rust-tls")]
+fn peer_certificate(stream: &MaybeHttpsStream<TcpStream>) -> Result<Option<Vec<u8>>> {
+    match stream {
+        MaybeHttpsStream::Https(tls) => Ok(tls
+            .get_ref()
+            .1
+            .peer_certificates()
+            .and_then(|certs| certs.first())
+            .map(|cert| cert.0.clone())),
+        MaybeHttpsStream::Http(_) => Ok(None),
+    }
+}
+
+/// Returns the certificate of the server (in the DER format), or `None`
+/// for a connection which does not use TLS.
+#[cfg(not(feature = "rust-tls"))]
+fn peer_certificate(stream: &MaybeHttpsStream<TcpStream>) -> Result<Option<Vec<u8>>> {
+    match stream {
+        MaybeHttpsStream::Https(tls) => match tls.get_ref().peer_certificate()? {
+            Some(cert) => Ok(Some(cert.to_der()?)),
+            None => Ok(None),
+        },
+        MaybeHttpsStream::Http(_) => Ok(None),
+    }
+}
+
+/// Parse the certificates in a PEM bundle, in the DER format.
+#[cfg(feature = "rust-tls")]
+fn parse_pem_certificates(pem: &[u8]) -> Result<Vec<Vec<u8>>> {
+    Ok(rustls_pemfile::certs(&mut &pem[..])?)
+}
+
+/// Parse the certificates in a PEM bundle, in the DER format.
+#[cfg(not(feature = "rust-tls"))]
+fn parse_pem_certificates(pem: &[u8]) -> Result<Vec<Vec<u8>>> {
+    native_tls::Certificate::stack_from_pem(pem)?
+        .iter()
+        .map(|cert| Ok(cert.to_der()?))
+        .collect()
+}
+
+/// Parse a SHA-256 `fingerprint` in hex, ignoring any colons.
+fn parse_fingerprint(fingerprint: &str) -> Result<Fingerprint> {
+    let invalid = || {
+        Box::new(Error::new(
+            ErrorKind::InvalidInput,
+            format!("Invalid SHA-256 fingerprint: {}", fingerprint),
+        ))
+    };
+
+    let digits: Vec<u8> = fingerprint
+        .bytes()
+        .filter(|b| *b != b':')
+        .map(|b| (b as char).to_digit(16).map(|d| d as u8))
+        .collect::<Option<_>>()
+        .ok_or_else(invalid)?;
+
+    if digits.len() != 64 {
+        return Err(invalid());
+    }
+
+    let mut parsed = Fingerprint::default();
+    for (byte, pair) in parsed.iter_mut().zip(digits.chunks(2)) {
+        *byte = pair[0] << 4 | pair[1];
+    }
+
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{MockServer, TLS_CA_PEM, TLS_SERVER_FINGERPRINT};
+    use crate::SmartsheetApi;
+
+    const LIST_SHEETS: &str = r#"{"pageNumber": 1, "totalCount": 0, "totalPages": 1, "data": []}"#;
+
+    fn api(server: &MockServer, tls: TlsConfig) -> Result<SmartsheetApi> {
+        SmartsheetApi::from_endpoint_and_token(&server.tls_url(""), "my-token")
+            .with_proxy(None)
+            .with_tls_config(tls)
+    }
+
+    #[test]
+    fn test_parse_fingerprint() {
+        let parsed = parse_fingerprint(TLS_SERVER_FINGERPRINT).unwrap();
+        assert_eq!(parsed[0], 0x98);
+        assert_eq!(parsed[31], 0x2F);
+
+        let lowercase = TLS_SERVER_FINGERPRINT.replace(':', "").to_lowercase();
+        assert_eq!(parse_fingerprint(&lowercase).unwrap(), parsed);
+
+        assert!(parse_fingerprint("98:FB").is_err());
+        assert!(parse_fingerprint(&"zz".repeat(32)).is_err());
+    }
+
+    #[test]
+    fn test_add_root_certificates_pem() {
+        let tls = TlsConfig::new()
+            .add_root_certificates_pem(TLS_CA_PEM)
+            .unwrap();
+        assert_eq!(tls.root_certificates.len(), 1);
+
+        assert!(TlsConfig::new().add_root_certificates_pem(b"").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_private_ca() {
+        let server = MockServer::start_tls(vec![(200, LIST_SHEETS)]);
+
+        let tls = TlsConfig::new()
+            .add_root_certificates_pem(TLS_CA_PEM)
+            .unwrap()
+            .with_min_version(TlsVersion::Tls12);
+
+        api(&server, tls).unwrap().list_sheets().await.unwrap();
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_untrusted_ca() {
+        let server = MockServer::start_tls(vec![(200, LIST_SHEETS)]);
+
+        let tls = TlsConfig::new()
+            .add_root_certificates_pem(TLS_CA_PEM)
+            .unwrap();
+        let untrusted = TlsConfig::new();
+
+        assert!(api(&server, untrusted)
+            .unwrap()
+            .list_sheets()
+            .await
+            .is_err());
+        // The server is still up, as the rejected connection is skipped
+        api(&server, tls).unwrap().list_sheets().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_pinned_certificate() {
+        let server = MockServer::start_tls(vec![(200, LIST_SHEETS)]);
+
+        let tls = TlsConfig::new()
+            .add_root_certificates_pem(TLS_CA_PEM)
+            .unwrap();
+
+        let wrong_pin = tls
+            .clone()
+            .with_pinned_certificate(&"00".repeat(32))
+            .unwrap();
+        let err = api(&server, wrong_pin)
+            .unwrap()
+            .list_sheets()
+            .await
+            .unwrap_err();
+        assert!(format!("{:?}", err).contains("pinned certificate"));
+
+        let pinned = tls.with_pinned_certificate(TLS_SERVER_FINGERPRINT).unwrap();
+        api(&server, pinned).unwrap().list_sheets().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_pinned_certificate_refuses_plain_http() {
+        let server = MockServer::start(vec![(200, LIST_SHEETS)]);
+
+        let pinned = TlsConfig::new()
+            .with_pinned_certificate(TLS_SERVER_FINGERPRINT)
+            .unwrap();
+        let smart = SmartsheetApi::from_endpoint_and_token(&server.url(""), "my-token")
+            .with_proxy(None)
+            .with_tls_config(pinned)
+            .unwrap();
+
+        let err = smart.list_sheets().await.unwrap_err();
+        assert!(format!("{:?}", err).contains("PermissionDenied"));
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn test_no_roots() {
+        let tls = TlsConfig::new().without_native_roots();
+        if cfg!(feature = "rust-tls") {
+            assert!(tls.build().is_err());
+        }
+
+        let tls = tls.add_root_certificates_pem(TLS_CA_PEM).unwrap();
+        assert!(tls.build().is_ok());
+    }
+}
@@ -1,5 +1,6 @@
 //! Library-specific utilities, mainly for internal use.
 //!
+use crate::telemetry::RequestSpan;
 use crate::types::Result;
 
 use std::io::{BufReader, Read};
@@ -17,6 +18,8 @@ pub async fn resp_into_struct<T>(resp: Response<Body>) -> Result<T>
 where
     T: de::DeserializeOwned,
 {
+    let span = RequestSpan::of(&resp);
+
     // asynchronously aggregate the chunks of the body
     let body = hyper::body::aggregate(resp).await?;
 
@@ -24,27 +27,38 @@ where
     let reader = BufReader::new(body.reader());
 
     // try to parse as json with serde_json
-    Ok(serde_json::from_reader(reader)?)
+    Ok(RequestSpan::deserialize(span, || {
+        serde_json::from_reader(reader)
+    })?)
 }
 
 pub async fn into_struct_from_str<T>(resp: Response<Body>) -> Result<T>
 where
     T: de::DeserializeOwned,
 {
+    let span = RequestSpan::of(&resp);
+
     let body_bytes = hyper::body::to_bytes(resp.into_body()).await?;
+    let body_str = std::str::from_utf8(&body_bytes)?;
 
-    Ok(serde_json::from_str(std::str::from_utf8(&body_bytes)?)?)
+    Ok(RequestSpan::deserialize(span, || {
+        serde_json::from_str(body_str)
+    })?)
 }
 
 pub async fn into_struct_from_slice<T>(resp: Response<Body>) -> Result<T>
 where
     T: de::DeserializeOwned,
 {
+    let span = RequestSpan::of(&resp);
+
     // asynchronously concatenate the buffer from a body into bytes
     let bytes = hyper::body::to_bytes(resp).await?;
 
     // try to parse as json with serde_json
-    Ok(serde_json::from_slice(&bytes)?)
+    Ok(RequestSpan::deserialize(span, || {
+        serde_json::from_slice(&bytes)
+    })?)
 }
 
 /// Read the body content of a mutable reference to a `Response` object
@@ -62,3 +76,75 @@ pub async fn resp_to_string(resp: &mut Response<Body>) -> Result<String> {
 
     Ok(body_string)
 }
+
+/// Percent-encode a string value, so that it can be safely used as a query
+/// parameter value in a URL.
+pub fn encode_uri_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+
+    encoded
+}
+
+/// Decode a percent-encoded string value, such as the user name in a URL.
+///
+/// Invalid escape sequences are left as-is.
+pub fn decode_uri_component(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Encode `data` in (standard, padded) Base64, as used for the credentials
+/// in *basic* authentication.
+pub fn base64_encode(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(data.chunks(3).len() * 4);
+
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
@@ -0,0 +1,22 @@
+//! Ensures the client never sends requests - and so the access token - to
+//! an endpoint over plain HTTP.
+
+use smartsheet_rs::SmartsheetApi;
+
+use std::io::ErrorKind;
+use std::net::TcpListener;
+
+#[tokio::test]
+async fn test_http_endpoint_is_refused() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let endpoint = format!("http://{}/2.0/", listener.local_addr().unwrap());
+
+    let smart = SmartsheetApi::from_endpoint_and_token(&endpoint, "my-token");
+
+    assert!(smart.list_sheets().await.is_err());
+
+    // The connection is refused before it is made, so nothing is accepted
+    listener.set_nonblocking(true).unwrap();
+    let err = listener.accept().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::WouldBlock);
+}
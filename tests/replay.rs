@@ -0,0 +1,40 @@
+//! Replays recorded interactions with the Smartsheet API, so that the
+//! deserialization of real responses is tested without an access token.
+//!
+//! Run with `cargo test --features testing`.
+
+use smartsheet_rs::models::{Cell, CellValue, Message, Row};
+use smartsheet_rs::testing::Cassette;
+use smartsheet_rs::SmartsheetApi;
+
+const CASSETTE: &str = "tests/cassettes/sheet_and_rows.json";
+const SHEET_ID: u64 = 4583173393803140;
+
+#[tokio::test]
+async fn test_get_sheet_and_add_rows() {
+    let cassette = Cassette::replay(CASSETTE).unwrap();
+    let smart = SmartsheetApi::from_token("").with_middleware(cassette);
+
+    let sheet = smart.get_sheet(SHEET_ID).await.unwrap();
+
+    assert_eq!(sheet.name, "Project Tracker");
+    assert_eq!(sheet.columns.len(), 2);
+    assert_eq!(
+        sheet.rows[0].cells[0].value_as_str().unwrap(),
+        "Write the docs"
+    );
+
+    let column_id = sheet.columns[0].id;
+    let cell = Cell {
+        column_id,
+        value: Some(CellValue::Text("Review the docs".to_owned())),
+        ..Default::default()
+    };
+    let row = Row::with_cells([cell]).to_bottom(true);
+
+    let result = smart.add_rows(SHEET_ID, [row]).await.unwrap();
+
+    assert_eq!(result.message, Message::Success);
+    assert_eq!(result.result[0].row_number, 2);
+    assert_eq!(result.result[0].sheet_id, Some(SHEET_ID));
+}